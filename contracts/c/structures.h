@@ -72,6 +72,12 @@ typedef enum Action
     RequestBurn,
     ConfirmBurn,
     RejectBurn,
+    CancelMint,
+    CancelBurn,
+    ConfirmMintPartial,
+    ForceCloseTick,
+    ProposeCustodians,
+    CancelCustodianProposal,
     Others,
 } Action;
 
@@ -89,9 +95,15 @@ const char *ACTIONS[] = {
     "request_burn",
     "confirm_burn",
     "reject_burn",
+    "cancel_mint",
+    "cancel_burn",
+    "confirm_mint_partial",
+    "force_close_tick",
+    "propose_custodians",
+    "cancel_custodian_proposal",
     "Others"};
 
-#define NUM_ACTIONS 14 // Number of elements in the array
+#define NUM_ACTIONS 20 // Number of elements in the array
 /*
  * 0
 System status
@@ -28,7 +28,7 @@ pub fn main() -> Result<(), Box<dyn AsI8>> {
         util::find_cells_by_script_in_inputs_and_outputs(ScriptType::Type, self_script.as_reader())?;
     match action {
         DeployConfig => {
-            verifiers::permission::verify_input_has_owner_lock(0)?;
+            verifiers::permission::verify_inputs_have_owner_lock()?;
 
             verifiers::basic::verify_cell_number_and_position(
                 "ConfigCell",
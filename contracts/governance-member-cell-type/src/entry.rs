@@ -1,19 +1,25 @@
 use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
-use alloc::vec;
 use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cmp::Ordering;
 use core::result::Result;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::{debug, high_level};
-use contract_core::config::always_success_lock;
+use contract_core::config::{
+    always_success_lock, check_action_status, custodian_proposal_delay, is_custodian_cosign_required,
+};
 use contract_core::constants::{CellField, ScriptType};
-use contract_core::data_parser::governance_member_cell;
+use contract_core::data_parser::governance_member_cell::{self, VersionedGovernanceMembers};
 use contract_core::error::{AsI8, CoreError};
-use contract_core::{cc_assert, util, verifiers};
-use types::constants::{owner_lock, Action, GovernanceMemberRole};
-use types::packed::Script;
+use contract_core::{cc_assert, since_util, util, verifiers};
+use types::constants::{owner_lock, Action, GovernanceMemberRole, MULTISIG_MAX_MEMBERS, MULTISIG_MEMBER_SIZE};
+use types::packed::{CustodianProposal, GovernanceMembers, GovernanceMembersV1, Script, SmtUpdateAction};
 use types::prelude::{Entity, Reader};
+use types::smt::{SMT_UPDATE_INSERT, SMT_UPDATE_REMOVE, SMT_VALUE_EXISTING, SMT_VALUE_NOT_EXISTING};
+use types::util::blake2b_256;
 
 use super::error::GovernanceError;
 
@@ -28,11 +34,17 @@ pub fn main() -> Result<(), Box<dyn AsI8>> {
 
     debug!("==== Action {} ====", action.to_string());
 
+    check_action_status(action)?;
+
     match action {
         Action::InitGovernance => init_governance(input_governance_cells, output_governance_cells)?,
         Action::UpdateOwner => update_owner(input_governance_cells, output_governance_cells)?,
-        Action::UpdateCustodians => update_custodians(input_governance_cells, output_governance_cells)?,
+        Action::UpdateCustodians => update_custodians(&self_script, input_governance_cells, output_governance_cells)?,
         Action::UpdateMerchants => update_merchants(input_governance_cells, output_governance_cells)?,
+        Action::ProposeCustodians => propose_custodians(&self_script, input_governance_cells, output_governance_cells)?,
+        Action::CancelCustodianProposal => {
+            cancel_custodian_proposal(&self_script, input_governance_cells, output_governance_cells)?
+        }
         _ => {
             return Err(CoreError::ActionNotSupported {
                 action: action.to_string(),
@@ -48,7 +60,7 @@ fn init_governance(
     input_governance_cells: Vec<usize>,
     output_governance_cells: Vec<usize>,
 ) -> Result<(), Box<dyn AsI8>> {
-    verifiers::permission::verify_input_has_owner_lock(0)?;
+    verifiers::permission::verify_inputs_have_owner_lock()?;
 
     verifiers::basic::verify_cell_number_and_position(
         "GovernanceMemberCell",
@@ -83,6 +95,12 @@ fn init_governance(
 
             verify_the_merchant_cell_data(output_governance_cells[0])?;
         }
+        GovernanceMemberRole::CustodianProposal => {
+            return Err(GovernanceError::PermissionDenied {
+                msg: "The custodian proposal can only be created by propose_custodians.".to_string(),
+            }
+            .into());
+        }
     }
 
     Ok(())
@@ -117,9 +135,17 @@ fn update_owner(input_governance_cells: Vec<usize>, output_governance_cells: Vec
 }
 
 fn update_custodians(
+    self_script: &Script,
     input_governance_cells: Vec<usize>,
     output_governance_cells: Vec<usize>,
 ) -> Result<(), Box<dyn AsI8>> {
+    if load_self_role(self_script)? == GovernanceMemberRole::CustodianProposal {
+        verify_the_custodian_proposal_consumed(&input_governance_cells, &output_governance_cells)?;
+        verifiers::permission::verify_and_find_governance_cell(GovernanceMemberRole::Custodian, Source::Input)?;
+
+        return Ok(());
+    }
+
     verifiers::basic::verify_cell_number_and_position(
         "GovernanceMemberCell",
         &input_governance_cells,
@@ -143,6 +169,59 @@ fn update_custodians(
     )?;
 
     verify_the_custodian_cell_data(output_governance_cells[0])?;
+    verify_the_custodian_proposal_executed(input_governance_cells[0], output_governance_cells[0])?;
+
+    if is_custodian_cosign_required()? {
+        verify_the_current_custodians_cosigned(input_governance_cells[0])?;
+    }
+
+    Ok(())
+}
+
+fn propose_custodians(
+    self_script: &Script,
+    input_governance_cells: Vec<usize>,
+    output_governance_cells: Vec<usize>,
+) -> Result<(), Box<dyn AsI8>> {
+    cc_assert!(
+        load_self_role(self_script)? == GovernanceMemberRole::CustodianProposal,
+        GovernanceError::PermissionDenied {
+            msg: "This transaction can only create a custodian proposal.".to_string()
+        }
+    );
+
+    verifiers::permission::verify_inputs_have_owner_lock()?;
+
+    verifiers::basic::verify_cell_number_and_position(
+        "GovernanceMemberCell",
+        &input_governance_cells,
+        &[],
+        &output_governance_cells,
+        &[0],
+    )?;
+
+    let (_role, cell_id) = util::load_governance_member_type_info(output_governance_cells[0], Source::Output)?;
+    verify_cell_id_correct(&cell_id, output_governance_cells[0])?;
+
+    verify_the_custodian_cell_lock(output_governance_cells[0])?;
+    verify_the_custodian_proposal_data(output_governance_cells[0])?;
+
+    Ok(())
+}
+
+fn cancel_custodian_proposal(
+    self_script: &Script,
+    input_governance_cells: Vec<usize>,
+    output_governance_cells: Vec<usize>,
+) -> Result<(), Box<dyn AsI8>> {
+    cc_assert!(
+        load_self_role(self_script)? == GovernanceMemberRole::CustodianProposal,
+        GovernanceError::PermissionDenied {
+            msg: "This transaction can only cancel a custodian proposal.".to_string()
+        }
+    );
+
+    verify_the_custodian_proposal_consumed(&input_governance_cells, &output_governance_cells)?;
 
     Ok(())
 }
@@ -151,7 +230,7 @@ fn update_merchants(
     input_governance_cells: Vec<usize>,
     output_governance_cells: Vec<usize>,
 ) -> Result<(), Box<dyn AsI8>> {
-    let custodian_lock = verifiers::permission::verify_input_has_custodian_lock(1)?;
+    let (_, custodian_lock) = verifiers::permission::verify_input_has_custodian_lock()?;
 
     verifiers::basic::verify_cell_number_and_position(
         "GovernanceMemberCell",
@@ -176,7 +255,7 @@ fn update_merchants(
     )?;
 
     verify_the_merchant_cell_data(output_governance_cells[0])?;
-    verify_the_custodian_not_in_merchants(&custodian_lock, output_governance_cells[0])?;
+    verify_the_merchant_members_update(&custodian_lock, input_governance_cells[0], output_governance_cells[0])?;
 
     Ok(())
 }
@@ -198,6 +277,12 @@ fn verify_cell_id_correct(cell_id: &[u8], output_index: usize) -> Result<(), Gov
     Ok(())
 }
 
+fn load_self_role(self_script: &Script) -> Result<GovernanceMemberRole, Box<dyn AsI8>> {
+    let (role, _cell_id) = governance_member_cell::parse_type_args(self_script.as_reader().args().raw_data())?;
+
+    Ok(role)
+}
+
 fn verify_the_role_of_target(
     index: usize,
     expected_role: GovernanceMemberRole,
@@ -235,40 +320,184 @@ fn verify_the_custodian_cell_data(index: usize) -> Result<(), Box<dyn AsI8>> {
     let data = high_level::load_cell_data(index, Source::Output).map_err(GovernanceError::from)?;
     let (version, governance_members) = governance_member_cell::parse_data(&data)?;
 
-    match version {
-        0 => {
-            cc_assert!(
-                governance_members.parent_id().is_empty(),
-                GovernanceError::CustodianParentIdMustBeEmpty
-            );
+    match governance_members {
+        VersionedGovernanceMembers::V0(governance_members) => verify_the_custodian_members(&governance_members)?,
+        _ => return Err(GovernanceError::UnsupportedDataVersion { version }.into()),
+    }
 
-            let current_multisig_args = governance_members.multisig_args().as_reader().raw_data().to_vec();
+    Ok(())
+}
 
-            cc_assert!(
-                current_multisig_args.len() == 3,
-                GovernanceError::CustodianMultiSigArgsIsInvalid
-            );
+fn verify_the_custodian_members(governance_members: &GovernanceMembers) -> Result<(), Box<dyn AsI8>> {
+    cc_assert!(
+        governance_members.parent_id().is_empty(),
+        GovernanceError::CustodianParentIdMustBeEmpty
+    );
+
+    let current_multisig_args = governance_members.multisig_args().as_reader().raw_data().to_vec();
+
+    cc_assert!(
+        current_multisig_args.len() == 3,
+        GovernanceError::CustodianMultiSigArgsIsInvalid
+    );
 
-            let mut pubkey_hashes = vec![];
-            for member in governance_members.members().into_iter() {
-                pubkey_hashes.push(member.as_reader().raw_data().to_vec());
+    let mut pubkey_hashes = vec![];
+    let mut unique_pubkey_hashes = BTreeSet::new();
+    for (i, member) in governance_members.members().into_iter().enumerate() {
+        let pubkey_hash = member.as_reader().raw_data().to_vec();
+        cc_assert!(
+            pubkey_hash.len() == MULTISIG_MEMBER_SIZE,
+            GovernanceError::CustodianMemberIsInvalid {
+                index: i,
+                length: pubkey_hash.len()
             }
+        );
+        cc_assert!(
+            unique_pubkey_hashes.insert(pubkey_hash.clone()),
+            GovernanceError::CustodianMemberIsDuplicated {
+                member: hex::encode(&pubkey_hash)
+            }
+        );
 
-            let require_first_n = current_multisig_args[1];
-            let threshold = current_multisig_args[2];
-            let expected_lock_args = util::build_omni_lock_multisig_args(require_first_n, threshold, pubkey_hashes);
-            let current_lock_args = governance_members.as_reader().lock_args().raw_data();
+        pubkey_hashes.push(pubkey_hash);
+    }
 
-            cc_assert!(
-                current_lock_args == &expected_lock_args,
-                GovernanceError::CustodianLockArgsInDataIsInvalid {
-                    expected: hex::encode(&expected_lock_args),
-                    current: hex::encode(current_lock_args)
-                }
-            );
+    cc_assert!(
+        pubkey_hashes.len() <= MULTISIG_MAX_MEMBERS,
+        GovernanceError::CustodianMembersTooMany {
+            count: pubkey_hashes.len(),
+            max: MULTISIG_MAX_MEMBERS
         }
-        _ => return Err(GovernanceError::UnsupportedDataVersion { version }.into()),
-    }
+    );
+
+    let require_first_n = current_multisig_args[1];
+    let threshold = current_multisig_args[2];
+    // A threshold of 0 or more than the members makes the custodian lock unusable forever.
+    cc_assert!(
+        threshold > 0 && threshold as usize <= pubkey_hashes.len(),
+        GovernanceError::CustodianThresholdIsInvalid {
+            threshold,
+            members: pubkey_hashes.len()
+        }
+    );
+    cc_assert!(
+        require_first_n <= threshold,
+        GovernanceError::CustodianRequireFirstNIsInvalid {
+            require_first_n,
+            threshold
+        }
+    );
+
+    let expected_lock_args = util::build_omni_lock_multisig_args(require_first_n, threshold, pubkey_hashes);
+    let current_lock_args = governance_members.as_reader().lock_args().raw_data();
+
+    cc_assert!(
+        current_lock_args == expected_lock_args,
+        GovernanceError::CustodianLockArgsInDataIsInvalid {
+            expected: hex::encode(&expected_lock_args),
+            current: hex::encode(current_lock_args)
+        }
+    );
+
+    Ok(())
+}
+
+fn verify_the_custodian_proposal_data(index: usize) -> Result<(), Box<dyn AsI8>> {
+    debug!("Verify if the GovernanceMemberCell(custodian_proposal).data is valid.");
+
+    let data = high_level::load_cell_data(index, Source::Output).map_err(GovernanceError::from)?;
+    let (_version, proposal) = governance_member_cell::parse_proposal_data(&data)?;
+
+    let custodian_cell_index =
+        verifiers::permission::verify_and_find_governance_cell(GovernanceMemberRole::Custodian, Source::CellDep)?;
+    let (_role, custodian_id) = util::load_governance_member_type_info(custodian_cell_index, Source::CellDep)?;
+    verify_the_custodian_proposal_target(&proposal, &custodian_id)?;
+
+    verify_the_custodian_members(&proposal.members())?;
+
+    Ok(())
+}
+
+fn verify_the_current_custodians_cosigned(input_index: usize) -> Result<(), Box<dyn AsI8>> {
+    debug!("Verify if the current custodians co-sign the update.");
+
+    let custodian_lock = verifiers::permission::load_custodian_lock_of(input_index, Source::Input)?;
+    let cells = util::find_cells_by_script(ScriptType::Lock, custodian_lock.as_reader(), Source::Input)?;
+    cc_assert!(!cells.is_empty(), CoreError::CustodianLockIsRequired);
+
+    Ok(())
+}
+
+fn verify_the_custodian_proposal_target(
+    proposal: &CustodianProposal,
+    custodian_id: &[u8],
+) -> Result<(), Box<dyn AsI8>> {
+    let current = proposal.as_reader().custodian_id().raw_data();
+    cc_assert!(
+        current == custodian_id,
+        GovernanceError::CustodianProposalMismatch {
+            msg: format!(
+                "The custodian_id should be {}, but {} found.",
+                hex::encode(custodian_id),
+                hex::encode(current)
+            )
+        }
+    );
+
+    Ok(())
+}
+
+fn verify_the_custodian_proposal_consumed(
+    input_governance_cells: &[usize],
+    output_governance_cells: &[usize],
+) -> Result<(), Box<dyn AsI8>> {
+    verifiers::basic::verify_cell_number_range(
+        "GovernanceMemberCell(custodian_proposal)",
+        input_governance_cells,
+        (Ordering::Equal, 1),
+        output_governance_cells,
+        (Ordering::Equal, 0),
+    )?;
+
+    Ok(())
+}
+
+fn verify_the_custodian_proposal_executed(input_index: usize, output_index: usize) -> Result<(), Box<dyn AsI8>> {
+    debug!("Verify if the GovernanceMemberCell(custodian) is updated to an activated proposal.");
+
+    let proposal_index =
+        verifiers::permission::verify_and_find_governance_cell(GovernanceMemberRole::CustodianProposal, Source::Input)?;
+    let data = high_level::load_cell_data(proposal_index, Source::Input).map_err(GovernanceError::from)?;
+    let (_version, proposal) = governance_member_cell::parse_proposal_data(&data)?;
+
+    let (_role, custodian_id) = util::load_governance_member_type_info(input_index, Source::Input)?;
+    verify_the_custodian_proposal_target(&proposal, &custodian_id)?;
+
+    // The activation_epoch is chosen by the owner freely, so the window to react is measured from publication.
+    let delay = custodian_proposal_delay()?;
+    let since = high_level::load_input_since(proposal_index, Source::Input).map_err(GovernanceError::from)?;
+    cc_assert!(
+        since_util::is_relative_since_reached(since, since_util::build_relative_epoch_since(delay))?,
+        GovernanceError::CustodianProposalDelayNotReached { delay, since }
+    );
+
+    let activation_epoch = u64::from(proposal.activation_epoch());
+    let since = high_level::load_input_since(input_index, Source::Input).map_err(GovernanceError::from)?;
+    cc_assert!(
+        since_util::is_since_reached(since, since_util::build_absolute_epoch_since(activation_epoch))?,
+        GovernanceError::CustodianProposalNotActivated {
+            activation_epoch,
+            since
+        }
+    );
+
+    let data = high_level::load_cell_data(output_index, Source::Output).map_err(GovernanceError::from)?;
+    cc_assert!(
+        &data[1..] == proposal.members().as_slice(),
+        GovernanceError::CustodianProposalMismatch {
+            msg: "The GovernanceMemberCell(custodian).data should be the same as the proposal.members .".to_string()
+        }
+    );
 
     Ok(())
 }
@@ -277,14 +506,9 @@ fn verify_the_merchant_cell_data(index: usize) -> Result<(), Box<dyn AsI8>> {
     debug!("Verify if the GovernanceMemberCell.data is valid.");
 
     let data = high_level::load_cell_data(index, Source::Output).map_err(GovernanceError::from)?;
-    let (version, governance_members) = governance_member_cell::parse_data(&data)?;
-    match version {
-        0 => {
-            cc_assert!(
-                !governance_members.parent_id().is_empty(),
-                GovernanceError::MerchantParentIdMustNotBeEmpty
-            );
-
+    let (_version, governance_members) = governance_member_cell::parse_data(&data)?;
+    match governance_members {
+        VersionedGovernanceMembers::V0(governance_members) => {
             cc_assert!(
                 governance_members.lock_args().is_empty(),
                 GovernanceError::MerchantLockArgsMustBeEmpty
@@ -295,46 +519,147 @@ fn verify_the_merchant_cell_data(index: usize) -> Result<(), Box<dyn AsI8>> {
                 GovernanceError::MerchantMultisigArgsMustBeEmpty
             );
 
-            let custodian_cell_index = verifiers::permission::verify_and_find_governance_cell(
-                GovernanceMemberRole::Custodian,
-                Source::CellDep,
-            )?;
-            let (_role, expected_parent_id) =
-                util::load_governance_member_type_info(custodian_cell_index, Source::CellDep)?;
+            verify_the_merchant_parent_id(governance_members.as_reader().parent_id().raw_data())?;
+        }
+        VersionedGovernanceMembers::V1(governance_members) => {
+            verify_the_merchant_parent_id(governance_members.as_reader().parent_id().raw_data())?;
+        }
+    }
 
-            let parent_id = governance_members.as_reader().parent_id().raw_data();
-            cc_assert!(
-                parent_id == &expected_parent_id,
-                GovernanceError::MerchantParentIdMismatch {
-                    current: hex::encode(parent_id),
-                    expected: hex::encode(&expected_parent_id)
-                }
-            );
+    Ok(())
+}
+
+fn verify_the_merchant_parent_id(parent_id: &[u8]) -> Result<(), Box<dyn AsI8>> {
+    cc_assert!(!parent_id.is_empty(), GovernanceError::MerchantParentIdMustNotBeEmpty);
+
+    let custodian_cell_index =
+        verifiers::permission::verify_and_find_governance_cell(GovernanceMemberRole::Custodian, Source::CellDep)?;
+    let (_role, expected_parent_id) = util::load_governance_member_type_info(custodian_cell_index, Source::CellDep)?;
+
+    cc_assert!(
+        parent_id == expected_parent_id,
+        GovernanceError::MerchantParentIdMismatch {
+            current: hex::encode(parent_id),
+            expected: hex::encode(&expected_parent_id)
+        }
+    );
+
+    Ok(())
+}
+
+fn verify_the_merchant_members_update(
+    custodian_lock: &Script,
+    input_index: usize,
+    output_index: usize,
+) -> Result<(), Box<dyn AsI8>> {
+    let data = high_level::load_cell_data(input_index, Source::Input).map_err(GovernanceError::from)?;
+    let (input_version, input_members) = governance_member_cell::parse_data(&data)?;
+    let data = high_level::load_cell_data(output_index, Source::Output).map_err(GovernanceError::from)?;
+    let (output_version, output_members) = governance_member_cell::parse_data(&data)?;
+
+    match (input_members, output_members) {
+        (VersionedGovernanceMembers::V0(_), VersionedGovernanceMembers::V0(output_members)) => {
+            verify_the_custodian_not_in_merchants(custodian_lock, &output_members)?;
+        }
+        (VersionedGovernanceMembers::V1(input_members), VersionedGovernanceMembers::V1(output_members)) => {
+            verify_the_merchant_root_update(custodian_lock, input_index, &input_members, &output_members)?;
+        }
+        _ => {
+            return Err(GovernanceError::MerchantDataVersionMismatch {
+                input: input_version,
+                output: output_version,
+            }
+            .into())
         }
-        _ => return Err(GovernanceError::UnsupportedDataVersion { version }.into()),
     }
 
     Ok(())
 }
 
-fn verify_the_custodian_not_in_merchants(custodian_lock: &Script, index: usize) -> Result<(), Box<dyn AsI8>> {
+fn verify_the_custodian_not_in_merchants(
+    custodian_lock: &Script,
+    governance_members: &GovernanceMembers,
+) -> Result<(), Box<dyn AsI8>> {
     debug!("Verify if the custodian lock exists in GovernanceMemberCell.data.members .");
 
-    let data = high_level::load_cell_data(index, Source::Output).map_err(GovernanceError::from)?;
-    let (version, governance_members) = governance_member_cell::parse_data(&data)?;
-    match version {
-        0 => {
-            let custodian_lock_slice = custodian_lock.as_reader().as_slice();
-            for member in governance_members.members() {
-                debug!("member: {}", hex::encode(member.as_slice()));
-                cc_assert!(
-                    member.raw_data().as_ref() != custodian_lock_slice,
-                    GovernanceError::CustodianLockMustNotInMerchants
-                );
+    let custodian_lock_slice = custodian_lock.as_reader().as_slice();
+    for member in governance_members.members() {
+        debug!("member: {}", hex::encode(member.as_slice()));
+        cc_assert!(
+            member.raw_data().as_ref() != custodian_lock_slice,
+            GovernanceError::CustodianLockMustNotInMerchants
+        );
+    }
+
+    Ok(())
+}
+
+fn verify_the_merchant_root_update(
+    custodian_lock: &Script,
+    input_index: usize,
+    input_members: &GovernanceMembersV1,
+    output_members: &GovernanceMembersV1,
+) -> Result<(), Box<dyn AsI8>> {
+    debug!("Verify if the GovernanceMemberCell.data.members_root is updated by the SmtUpdateAction.");
+
+    let witness_args = high_level::load_witness_args(input_index, Source::Input).map_err(GovernanceError::from)?;
+    let update_action = match witness_args.input_type().to_opt() {
+        Some(bytes) => SmtUpdateAction::from_compatible_slice(&bytes.raw_data()).map_err(|_| {
+            GovernanceError::InvalidMerchantMembersUpdate {
+                msg: format!("witnesses[{}] Parse input_type to SmtUpdateAction failed.", input_index),
             }
+        })?,
+        None => {
+            return Err(GovernanceError::InvalidMerchantMembersUpdate {
+                msg: format!(
+                    "witnesses[{}] The input_type should contain SmtUpdateAction.",
+                    input_index
+                ),
+            }
+            .into());
         }
-        _ => return Err(GovernanceError::UnsupportedDataVersion { version }.into()),
+    };
+
+    let custodian_key = blake2b_256(custodian_lock.as_slice());
+    let mut keys = BTreeSet::new();
+    let mut leaves = Vec::new();
+    for item in update_action.updates().into_iter() {
+        let mut key = [0u8; 32];
+        key.copy_from_slice(item.key().as_slice());
+
+        cc_assert!(
+            keys.insert(key),
+            GovernanceError::InvalidMerchantMembersUpdate {
+                msg: format!("The key {} is updated more than once.", hex::encode(key)),
+            }
+        );
+
+        let (old_value, new_value) = match u8::from(item.packed_values()) {
+            SMT_UPDATE_INSERT => {
+                cc_assert!(key != custodian_key, GovernanceError::CustodianLockMustNotInMerchants);
+                (SMT_VALUE_NOT_EXISTING, SMT_VALUE_EXISTING)
+            }
+            SMT_UPDATE_REMOVE => (SMT_VALUE_EXISTING, SMT_VALUE_NOT_EXISTING),
+            packed_values => {
+                return Err(GovernanceError::InvalidMerchantMembersUpdate {
+                    msg: format!(
+                        "The packed_values 0x{:02x} of key {} is neither insert nor remove.",
+                        packed_values,
+                        hex::encode(key)
+                    ),
+                }
+                .into());
+            }
+        };
+        leaves.push((key, old_value, new_value));
     }
 
+    let mut old_root = [0u8; 32];
+    old_root.copy_from_slice(input_members.members_root().as_slice());
+    let mut new_root = [0u8; 32];
+    new_root.copy_from_slice(output_members.members_root().as_slice());
+
+    verifiers::smt::verify_smt_update(&old_root, &new_root, leaves, &update_action.proof().raw_data())?;
+
     Ok(())
 }
@@ -45,6 +45,32 @@ pub enum GovernanceError {
     PermissionDenied { msg: String },
     #[error("The omni-lock of custodians must not exsit in GovernanceMemberCell(merchants).data.members .")]
     CustodianLockMustNotInMerchants,
+    #[error(
+        "The data version of GovernanceMemberCell(merchant) can not be changed.(input: {input}, output: {output})"
+    )]
+    MerchantDataVersionMismatch { input: u8, output: u8 },
+    #[error("The GovernanceMemberCell(merchant).data.members_root is updated incorrectly: {msg}")]
+    InvalidMerchantMembersUpdate { msg: String },
+    #[error(
+        "The custodian proposal is not activated yet.(activation_epoch: {activation_epoch}, since: 0x{since:016x})"
+    )]
+    CustodianProposalNotActivated { activation_epoch: u64, since: u64 },
+    #[error("The custodian proposal is published less than {delay} epochs ago.(since: 0x{since:016x})")]
+    CustodianProposalDelayNotReached { delay: u64, since: u64 },
+    #[error("The custodian proposal does not match: {msg}")]
+    CustodianProposalMismatch { msg: String },
+    #[error("The GovernanceMemberCell(custodian).data.members[{index}] should be 20 bytes, but {length} bytes found.")]
+    CustodianMemberIsInvalid { index: usize, length: usize },
+    #[error("The GovernanceMemberCell(custodian).data.members contains duplicated member 0x{member} .")]
+    CustodianMemberIsDuplicated { member: String },
+    #[error("The GovernanceMemberCell(custodian).data.members should be no more than {max}, but {count} found.")]
+    CustodianMembersTooMany { count: usize, max: usize },
+    #[error("The threshold of custodian multisig should be in [1, {members}], but {threshold} found.")]
+    CustodianThresholdIsInvalid { threshold: u8, members: usize },
+    #[error(
+        "The require_first_n {require_first_n} of custodian multisig should not exceed the threshold {threshold}."
+    )]
+    CustodianRequireFirstNIsInvalid { require_first_n: u8, threshold: u8 },
 }
 
 impl From<SysError> for GovernanceError {
@@ -92,6 +118,25 @@ impl AsI8 for GovernanceError {
             } => 15,
             GovernanceError::PermissionDenied { msg: _ } => 16,
             GovernanceError::CustodianLockMustNotInMerchants => 17,
+            GovernanceError::MerchantDataVersionMismatch { input: _, output: _ } => 18,
+            GovernanceError::InvalidMerchantMembersUpdate { msg: _ } => 19,
+            GovernanceError::CustodianProposalNotActivated {
+                activation_epoch: _,
+                since: _,
+            } => 20,
+            GovernanceError::CustodianProposalMismatch { msg: _ } => 21,
+            GovernanceError::CustodianMemberIsInvalid { index: _, length: _ } => 22,
+            GovernanceError::CustodianMemberIsDuplicated { member: _ } => 23,
+            GovernanceError::CustodianMembersTooMany { count: _, max: _ } => 24,
+            GovernanceError::CustodianThresholdIsInvalid {
+                threshold: _,
+                members: _,
+            } => 25,
+            GovernanceError::CustodianRequireFirstNIsInvalid {
+                require_first_n: _,
+                threshold: _,
+            } => 26,
+            GovernanceError::CustodianProposalDelayNotReached { delay: _, since: _ } => 27,
         }
     }
 }
@@ -0,0 +1,561 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use contract_core::cc_assert;
+
+use crate::error::TickError;
+
+pub const TX_HASH_SIZE: usize = 32;
+
+const SLIP44_HARDENED: u32 = 0x8000_0000;
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The chains supported by TickCell, the values are the coin types defined in SLIP-44.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[repr(u32)]
+pub enum Chain {
+    Bitcoin = 0,
+    // SLIP-44 uses coin type 1 for the testnets of all coins, it is treated as the testnet of Bitcoin here.
+    Testnet = 1,
+    Litecoin = 2,
+    Dogecoin = 3,
+    Ethereum = 60,
+}
+
+impl Chain {
+    /// The TickCell.data.coin_type is a hardened SLIP-44 coin type in 4 bytes BE, such as 0x80000000 for Bitcoin.
+    pub fn from_coin_type(coin_type: &[u8]) -> Result<Self, TickError> {
+        let bytes: [u8; 4] = coin_type.try_into().map_err(|_| TickError::UnsupportedCoinType {
+            coin_type: hex::encode(coin_type),
+        })?;
+        let value = u32::from_be_bytes(bytes);
+
+        cc_assert!(
+            value & SLIP44_HARDENED != 0,
+            TickError::UnsupportedCoinType {
+                coin_type: hex::encode(coin_type),
+            }
+        );
+
+        match value & !SLIP44_HARDENED {
+            0 => Ok(Chain::Bitcoin),
+            1 => Ok(Chain::Testnet),
+            2 => Ok(Chain::Litecoin),
+            3 => Ok(Chain::Dogecoin),
+            60 => Ok(Chain::Ethereum),
+            _ => Err(TickError::UnsupportedCoinType {
+                coin_type: hex::encode(coin_type),
+            }),
+        }
+    }
+
+    fn bech32_hrp(&self) -> Option<&'static str> {
+        match self {
+            Chain::Bitcoin => Some("bc"),
+            Chain::Testnet => Some("tb"),
+            Chain::Litecoin => Some("ltc"),
+            Chain::Dogecoin | Chain::Ethereum => None,
+        }
+    }
+
+    /// The version bytes of P2PKH and P2SH addresses in base58check.
+    fn base58_versions(&self) -> &'static [u8] {
+        match self {
+            Chain::Bitcoin => &[0x00, 0x05],
+            Chain::Testnet => &[0x6f, 0xc4],
+            Chain::Litecoin => &[0x30, 0x32, 0x05],
+            Chain::Dogecoin => &[0x1e, 0x16],
+            Chain::Ethereum => &[],
+        }
+    }
+
+    /// The size of transaction hashes on the chain, all the supported chains use 32 bytes hashes for now.
+    pub fn tx_hash_size(&self) -> usize {
+        match self {
+            Chain::Bitcoin | Chain::Testnet | Chain::Litecoin | Chain::Dogecoin | Chain::Ethereum => TX_HASH_SIZE,
+        }
+    }
+
+    pub fn verify_tx_hash(&self, tx_hash: &[u8]) -> Result<(), TickError> {
+        cc_assert!(
+            tx_hash.len() == self.tx_hash_size(),
+            TickError::InvalidTickTxHashSize {
+                expected: self.tx_hash_size(),
+                current: tx_hash.len()
+            }
+        );
+
+        Ok(())
+    }
+
+    pub fn verify_address(&self, address: &[u8]) -> Result<(), TickError> {
+        let invalid = |msg: &str| TickError::InvalidReceiptAddress {
+            chain: format!("{:?}", self),
+            address: String::from_utf8_lossy(address).to_string(),
+            msg: msg.to_string(),
+        };
+
+        let address = core::str::from_utf8(address).map_err(|_| invalid("The address should be a UTF-8 string."))?;
+        let result = match self {
+            Chain::Ethereum => verify_evm_address(address),
+            _ => {
+                let is_bech32 = match self.bech32_hrp() {
+                    Some(hrp) => {
+                        address.len() > hrp.len()
+                            && address.as_bytes()[..hrp.len() + 1].eq_ignore_ascii_case(format!("{}1", hrp).as_bytes())
+                    }
+                    None => false,
+                };
+
+                if is_bech32 {
+                    verify_segwit_address(self.bech32_hrp().unwrap(), address)
+                } else {
+                    verify_base58_address(self.base58_versions(), address)
+                }
+            }
+        };
+
+        result.map_err(invalid)
+    }
+}
+
+fn verify_evm_address(address: &str) -> Result<(), &'static str> {
+    let hex_str = address
+        .strip_prefix("0x")
+        .ok_or("The EVM address should start with 0x.")?;
+
+    if hex_str.len() != 40 || !hex_str.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err("The EVM address should be 20 bytes in hex.");
+    }
+
+    Ok(())
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+
+    let mut chk: u32 = 1;
+    for &value in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x01ff_ffff) << 5) ^ value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+
+    chk
+}
+
+/// Verify a segwit address with BIP-173 and BIP-350, the witness version 0 uses bech32 and the others use bech32m.
+fn verify_segwit_address(hrp: &str, address: &str) -> Result<(), &'static str> {
+    if address.len() > 90 {
+        return Err("The bech32 address is too long.");
+    }
+    let has_lower = address.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = address.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err("The bech32 address should not be mixed case.");
+    }
+
+    let address = address.to_ascii_lowercase();
+    let data_part = &address[hrp.len() + 1..];
+    if data_part.len() < 7 {
+        return Err("The bech32 address is too short.");
+    }
+
+    let mut data = Vec::with_capacity(data_part.len());
+    for c in data_part.bytes() {
+        match BECH32_CHARSET.iter().position(|&x| x == c) {
+            Some(value) => data.push(value as u8),
+            None => return Err("The bech32 address contains invalid characters."),
+        }
+    }
+
+    let mut values: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+    values.push(0);
+    values.extend(hrp.bytes().map(|b| b & 0x1f));
+    values.extend(&data);
+
+    let witness_version = data[0];
+    let expected_const = if witness_version == 0 {
+        BECH32_CONST
+    } else {
+        BECH32M_CONST
+    };
+    if witness_version > 16 || bech32_polymod(&values) != expected_const {
+        return Err("The checksum of the bech32 address is invalid.");
+    }
+
+    // Convert the program from 5 bits groups to bytes, the padding must be zeros and less than 5 bits.
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut program_len = 0usize;
+    for &value in &data[1..data.len() - 6] {
+        acc = ((acc << 5) | value as u32) & 0xffff;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            program_len += 1;
+        }
+    }
+    if bits >= 5 || (acc & ((1 << bits) - 1)) != 0 {
+        return Err("The padding of the bech32 address is invalid.");
+    }
+
+    if !(2..=40).contains(&program_len) || (witness_version == 0 && program_len != 20 && program_len != 32) {
+        return Err("The witness program length of the bech32 address is invalid.");
+    }
+
+    Ok(())
+}
+
+/// Verify a base58check address, it must be 1 byte version, 20 bytes hash and 4 bytes checksum.
+fn verify_base58_address(versions: &[u8], address: &str) -> Result<(), &'static str> {
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in address.bytes() {
+        let mut carry = match BASE58_ALPHABET.iter().position(|&x| x == c) {
+            Some(value) => value as u32,
+            None => return Err("The base58 address contains invalid characters."),
+        };
+        for byte in bytes.iter_mut().rev() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    let leading_zeros = address.bytes().take_while(|&b| b == b'1').count();
+    let mut decoded = vec![0u8; leading_zeros];
+    decoded.extend(bytes);
+
+    if decoded.len() != 25 {
+        return Err("The base58 address should be 25 bytes.");
+    }
+    if !versions.contains(&decoded[0]) {
+        return Err("The version of the base58 address is not supported.");
+    }
+
+    let checksum = sha256(&sha256(&decoded[..21]));
+    if checksum[..4] != decoded[21..] {
+        return Err("The checksum of the base58 address is invalid.");
+    }
+
+    Ok(())
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+        0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+        0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+        0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+        0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+        0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+        0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+    let mut h: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend(&((data.len() as u64) * 8).to_be_bytes());
+
+    for chunk in message.chunks(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in chunk.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 = hh
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        for (x, y) in h.iter_mut().zip([a, b, c, d, e, f, g, hh]) {
+            *x = x.wrapping_add(y);
+        }
+    }
+
+    let mut ret = [0u8; 32];
+    for (i, x) in h.iter().enumerate() {
+        ret[i * 4..i * 4 + 4].copy_from_slice(&x.to_be_bytes());
+    }
+
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain_of(coin_type: u32) -> Chain {
+        Chain::from_coin_type(&(SLIP44_HARDENED | coin_type).to_be_bytes()).unwrap()
+    }
+
+    fn assert_valid(chain: Chain, addresses: &[&str]) {
+        for address in addresses {
+            assert!(
+                chain.verify_address(address.as_bytes()).is_ok(),
+                "{} should be valid on {:?}",
+                address,
+                chain
+            );
+        }
+    }
+
+    fn assert_invalid(chain: Chain, addresses: &[&str]) {
+        for address in addresses {
+            assert!(
+                chain.verify_address(address.as_bytes()).is_err(),
+                "{} should be invalid on {:?}",
+                address,
+                chain
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_coin_type() {
+        assert_eq!(chain_of(0), Chain::Bitcoin);
+        assert_eq!(chain_of(1), Chain::Testnet);
+        assert_eq!(chain_of(2), Chain::Litecoin);
+        assert_eq!(chain_of(3), Chain::Dogecoin);
+        assert_eq!(chain_of(60), Chain::Ethereum);
+    }
+
+    #[test]
+    fn challenge_from_coin_type() {
+        // Not hardened
+        assert!(Chain::from_coin_type(&0u32.to_be_bytes()).is_err());
+        // Not supported
+        assert!(Chain::from_coin_type(&(SLIP44_HARDENED | 145).to_be_bytes()).is_err());
+        // Not 4 bytes
+        assert!(Chain::from_coin_type(&[0x80, 0x00, 0x00]).is_err());
+        assert!(Chain::from_coin_type(&[0x80, 0x00, 0x00, 0x00, 0x00]).is_err());
+        // Not BE
+        assert!(Chain::from_coin_type(&SLIP44_HARDENED.to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_verify_tx_hash() {
+        for coin_type in [0, 1, 2, 3, 60] {
+            let chain = chain_of(coin_type);
+            assert!(chain.verify_tx_hash(&[0u8; 32]).is_ok());
+            assert!(chain.verify_tx_hash(&[0u8; 31]).is_err());
+            assert!(chain.verify_tx_hash(&[0u8; 33]).is_err());
+            assert!(chain.verify_tx_hash(&[]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_bitcoin_address() {
+        assert_valid(
+            Chain::Bitcoin,
+            &[
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+                "bc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0szrtjt7",
+                "bc1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sg5tmnz",
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+                "3CNHUhP3uyB9EUtRLsmvFUmvGdjGdkTxJw",
+            ],
+        );
+    }
+
+    #[test]
+    fn challenge_bitcoin_address() {
+        assert_invalid(
+            Chain::Bitcoin,
+            &[
+                // Bad checksum
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+                "bc1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sg5tmnq",
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMJ",
+                // Witness version 0 with bech32m checksum
+                "bc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sg5tmnz",
+                // Wrong HRP
+                "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+                "ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9",
+                // Mixed case
+                "bc1qW508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                // Wrong length
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kqq7e2cw9",
+                "13RJa7YdZQz3JHotw6gx1sco2AAPDrMZM",
+                // Wrong version
+                "mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r",
+                "DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE",
+                // Invalid characters
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAM0",
+                "",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_testnet_address() {
+        assert_valid(
+            Chain::Testnet,
+            &[
+                "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+                "tb1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0s4taa33",
+                "tb1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0slua5fd",
+                "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                "mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8r",
+                "2N3vVYSK5XRgVSGWy21PnsRmBUywSQNdCsf",
+            ],
+        );
+    }
+
+    #[test]
+    fn challenge_testnet_address() {
+        assert_invalid(
+            Chain::Testnet,
+            &[
+                // Bad checksum
+                "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsq",
+                "mrCDrCybB6J1vRfbwM5hemdJz73FwDBC8s",
+                // Wrong HRP
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                // Mixed case
+                "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzSX",
+                // Wrong length
+                "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kqqyg0uw8",
+                "BALJs7nu9dYVkpjH5A5h2JpCKq3HQnWPi",
+                // Wrong version
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_litecoin_address() {
+        assert_valid(
+            Chain::Litecoin,
+            &[
+                "ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9",
+                "ltc1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sp89z3m",
+                "ltc1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sts9tf8",
+                "LVuDpNCSSj6pQ7t9Pv6d6sUkLKoqDEVUnJ",
+                "MJaRnao1s62a2zAKSkmG582KbLKianqb7v",
+                "3CNHUhP3uyB9EUtRLsmvFUmvGdjGdkTxJw",
+            ],
+        );
+    }
+
+    #[test]
+    fn challenge_litecoin_address() {
+        assert_invalid(
+            Chain::Litecoin,
+            &[
+                // Bad checksum
+                "ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n8",
+                "LVuDpNCSSj6pQ7t9Pv6d6sUkLKoqDEVUnK",
+                // Wrong HRP
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                // Mixed case
+                "LTC1qw508d6qejxtdg4y5r3zarvary0c5xw7kgmn4n9",
+                // Wrong length
+                "ltc1qw508d6qejxtdg4y5r3zarvary0c5xw7kqq4dff0f",
+                "5RDjnAne2kXKSdT65HZYHjhizPqg6qEVc",
+                // Wrong version
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_dogecoin_address() {
+        assert_valid(
+            Chain::Dogecoin,
+            &[
+                "DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE",
+                "A37YDYSwz3438rFtm1SLVcQHyD7JeueC9H",
+            ],
+        );
+    }
+
+    #[test]
+    fn challenge_dogecoin_address() {
+        assert_invalid(
+            Chain::Dogecoin,
+            &[
+                // Bad checksum
+                "DFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLF",
+                // Dogecoin has no segwit address
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                // Base58 is case sensitive
+                "dFpN6QqFfUm3gKNaxN6tNcab1FArL9cZLE",
+                // Wrong length
+                "3n3aKu58rVwqe15teTz4wHfskZDAp5dDK",
+                // Wrong version
+                "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_ethereum_address() {
+        assert_valid(
+            Chain::Ethereum,
+            &[
+                "0x52908400098527886E0F7030069857D2E4169EE7",
+                "0xde709f2102306220921060314715629080e2fb77",
+            ],
+        );
+    }
+
+    #[test]
+    fn challenge_ethereum_address() {
+        assert_invalid(
+            Chain::Ethereum,
+            &[
+                // Without 0x
+                "52908400098527886E0F7030069857D2E4169EE7",
+                // Wrong length
+                "0x52908400098527886E0F7030069857D2E4169E",
+                "0x52908400098527886E0F7030069857D2E4169EE700",
+                // Not hex
+                "0x52908400098527886E0F7030069857D2E4169EZ7",
+                // Not an EVM address
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            ],
+        );
+    }
+}
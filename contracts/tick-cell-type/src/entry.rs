@@ -1,26 +1,41 @@
 use alloc::borrow::ToOwned;
 use alloc::boxed::Box;
-use alloc::collections::BTreeMap;
-use alloc::string::ToString;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use alloc::{format, vec};
 use core::cmp::Ordering;
 use core::result::Result;
+use core::slice;
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::ckb_types::prelude::*;
+use ckb_std::error::SysError;
 use ckb_std::high_level;
-use contract_core::config::{check_system_status, get_config_by_key};
-use contract_core::constants::ScriptType;
+use contract_core::config::{
+    check_action_status, check_system_status, check_token_status, deposit_registry_cell_type, fee_config,
+    get_config_by_key, mint_quota_cell_type, reserve_attestation_config, tick_receipt_type, xudt_supply_cell_type,
+    FeeConfig,
+};
+use contract_core::constants::{CellField, ScriptType};
 use contract_core::error::{AsI8, CoreError};
 use contract_core::util::{self};
-use contract_core::{cc_assert, debug, verifiers};
+use contract_core::{cc_assert, data_parser, debug, since_util, verifiers};
 use types::constants::Action::{self};
-use types::constants::{ConfigKey, TickType, TOKEN_ID_SIZE};
-use types::packed::{Byte32, Script};
+use types::constants::{ConfigKey, TickOutcome, TickType, TOKEN_ID_SIZE};
+use types::packed::{BurnPayout, Byte32, Script, SmtUpdateAction};
+use types::smt;
 
+use crate::chain::Chain;
 use crate::error::TickError;
-use crate::parser::{parse_tick, TickCellData};
+use crate::parser::{
+    parse_mint_quotas, parse_reserve_attestation, parse_tick, parse_xudt_supplies, MintQuotaData,
+    ReserveAttestationData, TickCellData,
+};
+
+const MAX_MEMO_SIZE: usize = 256;
+
+type XudtAmountMap = BTreeMap<(Vec<u8>, Vec<u8>), u128>;
 
 pub fn main() -> Result<(), Box<dyn AsI8>> {
     debug!("====== Running tick-cell-type ======");
@@ -41,13 +56,41 @@ pub fn main() -> Result<(), Box<dyn AsI8>> {
 
     debug!("==== Action {} ====", action.to_string());
 
+    // The escrow XudtCells of burn requests and the DepositRegistryCell are locked by this script too, so when it is
+    // running as a lock script only the actions which consume them are allowed.
+    if is_running_as_lock(&self_script)? {
+        verify_locked_cells_action(action)?;
+    }
+
+    check_action_status(action)?;
+
     match action {
-        Action::RequestMint => request(input_tick_cells, output_tick_cells, TickType::Mint)?,
+        Action::RequestMint => request(input_tick_cells, output_tick_cells, output_xudt_cells, TickType::Mint)?,
         Action::ConfirmMint => confirm_mint(input_tick_cells, output_tick_cells, input_xudt_cells, output_xudt_cells)?,
+        Action::ConfirmMintPartial => {
+            confirm_mint_partial(input_tick_cells, output_tick_cells, input_xudt_cells, output_xudt_cells)?
+        }
         Action::RejectMint => reject_mint(input_tick_cells, output_tick_cells, input_xudt_cells, output_xudt_cells)?,
-        Action::RequestBurn => request(input_tick_cells, output_tick_cells, TickType::Burn)?,
+        Action::RequestBurn => request(input_tick_cells, output_tick_cells, output_xudt_cells, TickType::Burn)?,
         Action::ConfirmBurn => confirm_burn(input_tick_cells, output_tick_cells, input_xudt_cells, output_xudt_cells)?,
         Action::RejectBurn => reject_burn(input_tick_cells, output_tick_cells, input_xudt_cells, output_xudt_cells)?,
+        Action::CancelMint => cancel(
+            input_tick_cells,
+            output_tick_cells,
+            input_xudt_cells,
+            output_xudt_cells,
+            TickType::Mint,
+        )?,
+        Action::CancelBurn => cancel(
+            input_tick_cells,
+            output_tick_cells,
+            input_xudt_cells,
+            output_xudt_cells,
+            TickType::Burn,
+        )?,
+        Action::ForceCloseTick => {
+            force_close_tick(input_tick_cells, output_tick_cells, input_xudt_cells, output_xudt_cells)?
+        }
         _ => {
             return Err(CoreError::ActionNotSupported {
                 action: action.to_string(),
@@ -62,12 +105,11 @@ pub fn main() -> Result<(), Box<dyn AsI8>> {
 fn request(
     input_tick_cells: Vec<usize>,
     output_tick_cells: Vec<usize>,
+    output_xudt_cells: Vec<usize>,
     tick_type: TickType,
 ) -> Result<(), Box<dyn AsI8>> {
     check_system_status()?;
 
-    verifiers::permission::verify_input_has_merchant_lock(0)?;
-
     verifiers::basic::verify_cell_number_and_position("TickCell", &input_tick_cells, &[], &output_tick_cells, &[0])?;
 
     let tick = load_tick_data(0, Source::Output)?;
@@ -75,7 +117,17 @@ fn request(
     verifiers::permission::verify_cell_has_always_success_lock(0, Source::Output)?;
 
     verify_if_tick_data_valid(tick_type, &tick)?;
-    verify_if_tick_belong_to_merchant(&tick.merchant, 0, Source::Input)?;
+    verifiers::permission::verify_input_has_merchant_lock(&tick.merchant)?;
+    check_token_status(&tick.token_id)?;
+
+    if tick.type_ == TickType::Mint {
+        verify_mint_request_value(&tick)?;
+    }
+
+    if tick.is_escrowed() {
+        cc_assert!(tick.type_ == TickType::Burn, TickError::OnlyBurnTickCanBeEscrowed);
+        verify_escrow_cell_created(&tick, &output_xudt_cells)?;
+    }
 
     Ok(())
 }
@@ -86,8 +138,8 @@ fn confirm_mint(
     input_xudt_cells: Vec<usize>,
     output_xudt_cells: Vec<usize>,
 ) -> Result<(), Box<dyn AsI8>> {
-    verifiers::permission::verify_input_has_custodian_lock(1)?;
-    verifiers::basic::verify_cell_number_and_position("TickCell", &input_tick_cells, &[0], &output_tick_cells, &[])?;
+    verify_batched_tick_cells_position(&input_tick_cells, &output_tick_cells)?;
+    let (custodian_index, _) = verifiers::permission::verify_input_has_custodian_lock()?;
     verifiers::basic::verify_cell_number_range(
         "XudtCell",
         &input_xudt_cells,
@@ -96,46 +148,383 @@ fn confirm_mint(
         (Ordering::Greater, 0),
     )?;
 
-    let tick = load_tick_data(0, Source::Input)?;
+    let ticks = load_tick_data_list(&input_tick_cells, Source::Input, TickType::Mint)?;
+    verify_ticks_before_deadline(&input_tick_cells, &ticks)?;
+    verify_mint(&ticks, output_xudt_cells)?;
+    verify_tick_receipts(&input_tick_cells, &ticks, custodian_index, TickOutcome::Confirmed, None)?;
+
+    Ok(())
+}
+
+// The residual of a partial confirmation is kept at outputs[0] with the original request except the value, its deposit
+// is registered already, so it can only be rejected or cancelled.
+fn confirm_mint_partial(
+    input_tick_cells: Vec<usize>,
+    output_tick_cells: Vec<usize>,
+    input_xudt_cells: Vec<usize>,
+    output_xudt_cells: Vec<usize>,
+) -> Result<(), Box<dyn AsI8>> {
+    let expected_outputs: &[usize] = if output_tick_cells.is_empty() { &[] } else { &[0] };
+    verifiers::basic::verify_cell_number_and_position(
+        "TickCell",
+        &input_tick_cells,
+        &[0],
+        &output_tick_cells,
+        expected_outputs,
+    )?;
+    let (custodian_index, _) = verifiers::permission::verify_input_has_custodian_lock()?;
+    verifiers::basic::verify_cell_number_range(
+        "XudtCell",
+        &input_xudt_cells,
+        (Ordering::Equal, 0),
+        &output_xudt_cells,
+        (Ordering::Greater, 0),
+    )?;
+
+    let mut ticks = load_tick_data_list(&input_tick_cells, Source::Input, TickType::Mint)?;
+    verify_ticks_before_deadline(&input_tick_cells, &ticks)?;
+    verify_tick_receipts(&input_tick_cells, &ticks, custodian_index, TickOutcome::Confirmed, None)?;
+    let tick = &mut ticks[0];
+
+    let confirmed = match output_tick_cells.first() {
+        Some(&index) => {
+            verifiers::permission::verify_cell_has_always_success_lock(index, Source::Output)?;
+            verifiers::basic::verify_cell_consistent_with_exception("TickCell", 0, index, vec![CellField::Data])?;
+
+            let residual = load_tick_data(index, Source::Output)?;
+            cc_assert!(
+                residual.is_same_request(tick),
+                TickError::InvalidResidualTickCell {
+                    msg: String::from("The residual TickCell must preserve the original request except the value.")
+                }
+            );
+            cc_assert!(
+                residual.value > 0 && residual.value < tick.value,
+                TickError::InvalidResidualTickCell {
+                    msg: format!(
+                        "The residual value {} should be in the range (0, {}).",
+                        residual.value, tick.value
+                    )
+                }
+            );
+
+            tick.value - residual.value
+        }
+        None => {
+            // Without the residual TickCell, the confirmed value is the total amount minted in this transaction.
+            let xudt_amount_map = collect_xudt_map(output_xudt_cells.clone(), Source::Output)?;
+            sum_by_token_id(&xudt_amount_map)?
+                .get(&tick.token_id)
+                .copied()
+                .unwrap_or(0)
+        }
+    };
+
+    debug!("The confirmed value of the TickCell is {}", confirmed);
 
     cc_assert!(
-        tick.type_ == TickType::Mint,
-        TickError::InvalidTickType {
-            current: tick.type_.to_string(),
-            expected: TickType::Mint.to_string()
+        confirmed > 0 && confirmed <= tick.value,
+        TickError::InvalidConfirmedValue {
+            confirmed,
+            value: tick.value
         }
     );
 
-    let (token_id, xudt_amount_map) = collect_xudt_map(output_xudt_cells, Source::Output)?;
+    tick.value = confirmed;
+    verify_mint(&ticks, output_xudt_cells)?;
+
+    Ok(())
+}
+
+fn verify_mint(ticks: &[TickCellData], output_xudt_cells: Vec<usize>) -> Result<(), Box<dyn AsI8>> {
+    let xudt_amount_map = collect_xudt_map(output_xudt_cells, Source::Output)?;
+    let fee = fee_config()?;
+
+    let tick_value_map = collect_tick_value_map(ticks)?;
+    let expected_amount_map = collect_mint_amount_map(ticks, fee.as_ref())?;
+    for tick in ticks.iter() {
+        check_token_status(&tick.token_id)?;
+        let key = (tick.token_id.clone(), tick.merchant.as_slice().to_vec());
+        verify_xudt_amount(
+            &xudt_amount_map,
+            &tick.token_id,
+            &tick.merchant,
+            expected_amount_map[&key],
+        )?;
+    }
+
+    let total_tick_value_map = sum_by_token_id(&tick_value_map)?;
+    if let Some(fee) = fee.as_ref() {
+        for token_id in total_tick_value_map.keys() {
+            let key = (token_id.to_owned(), fee.lock.as_slice().to_vec());
+            let expected_fee = expected_amount_map.get(&key).copied().unwrap_or(0);
+            verify_xudt_amount(&xudt_amount_map, token_id, &fee.lock, expected_fee)?;
+        }
+    }
+
+    // Every XudtCell minted in this transaction must be backed by a TickCell of the same token, so no extra tokens can
+    // be minted.
+    let minted_amount_map = sum_by_token_id(&xudt_amount_map)?;
+    for token_id in minted_amount_map.keys().chain(total_tick_value_map.keys()) {
+        let minted = minted_amount_map.get(token_id).copied().unwrap_or(0);
+        let expected = total_tick_value_map.get(token_id).copied().unwrap_or(0);
+
+        debug!(
+            "The token({}) minted {} and expected {}",
+            hex::encode(token_id),
+            minted,
+            expected
+        );
+
+        cc_assert!(
+            minted == expected,
+            TickError::MintedXudtAmountNotMatch { minted, expected }
+        );
+    }
+
+    verify_deposit_registry_update(ticks)?;
+    verify_mint_quota_update(ticks, &tick_value_map)?;
+    let supply_map = verify_xudt_supply_update(&total_tick_value_map, TickType::Mint)?;
+    verify_reserve_attestations(ticks, &supply_map)?;
+
+    Ok(())
+}
+
+// Every (coin_type, tx_hash) of the TickCells is inserted into the DepositRegistryCell, so a deposit is minted once.
+fn verify_deposit_registry_update(ticks: &[TickCellData]) -> Result<(), Box<dyn AsI8>> {
+    debug!("Verify if the DepositRegistryCell is updated with all deposits.");
+
+    let registry_type = deposit_registry_cell_type()?;
+    let (input_registry_cells, output_registry_cells) =
+        util::find_cells_by_script_in_inputs_and_outputs(ScriptType::Type, registry_type.as_reader())?;
+    verifiers::basic::verify_cell_number_range(
+        "DepositRegistryCell",
+        &input_registry_cells,
+        (Ordering::Equal, 1),
+        &output_registry_cells,
+        (Ordering::Equal, 1),
+    )?;
+
+    let input_index = input_registry_cells[0];
+    let output_index = output_registry_cells[0];
+    verifiers::basic::verify_cell_consistent_with_exception(
+        "DepositRegistryCell",
+        input_index,
+        output_index,
+        vec![CellField::Capacity, CellField::Data],
+    )?;
 
+    // The DepositRegistryCell is locked by this script, so it can only be updated by the transactions verified here.
+    let lock = high_level::load_cell_lock(input_index, Source::Input).map_err(TickError::from)?;
+    let self_script = high_level::load_script().map_err(TickError::from)?;
     cc_assert!(
-        token_id == tick.token_id,
-        TickError::XudtCellTokenIdMismatch {
-            expected: hex::encode(&tick.token_id),
-            current: hex::encode(&token_id),
+        lock.as_slice() == self_script.as_slice(),
+        TickError::InvalidDepositRegistryUpdate {
+            msg: String::from("The DepositRegistryCell should be locked by the tick-cell-type script."),
         }
     );
 
-    let merchant_lock = tick.merchant.as_slice();
-    match xudt_amount_map.get(merchant_lock) {
-        Some(amount) => {
-            cc_assert!(
-                *amount == tick.value,
-                TickError::XudtTransferError {
-                    target_lock: format!("{}", tick.merchant),
-                    token_id: hex::encode(&tick.token_id),
-                    amount: tick.value,
-                }
-            );
-        }
+    let data = high_level::load_cell_data(input_index, Source::Input).map_err(TickError::from)?;
+    let (_, old_root) = data_parser::deposit_registry_cell::parse_data(&data)?;
+    let data = high_level::load_cell_data(output_index, Source::Output).map_err(TickError::from)?;
+    let (_, new_root) = data_parser::deposit_registry_cell::parse_data(&data)?;
+
+    let mut keys = BTreeSet::new();
+    for tick in ticks.iter() {
+        let key = smt::deposit_key(&tick.coin_type, &tick.tx_hash);
+        cc_assert!(
+            keys.insert(key),
+            TickError::DuplicatedDeposit {
+                coin_type: hex::encode(&tick.coin_type),
+                tx_hash: hex::encode(&tick.tx_hash),
+            }
+        );
+    }
+
+    let witness_args = high_level::load_witness_args(input_index, Source::Input).map_err(TickError::from)?;
+    let update_action = match witness_args.input_type().to_opt() {
+        Some(bytes) => SmtUpdateAction::from_compatible_slice(&bytes.raw_data()).map_err(|_| {
+            TickError::InvalidDepositRegistryUpdate {
+                msg: format!("witnesses[{}] Parse input_type to SmtUpdateAction failed.", input_index),
+            }
+        })?,
         None => {
-            return Err(TickError::XudtTransferError {
-                target_lock: format!("{}", tick.merchant),
-                token_id: hex::encode(&tick.token_id),
-                amount: tick.value,
+            return Err(TickError::InvalidDepositRegistryUpdate {
+                msg: format!(
+                    "witnesses[{}] The input_type should contain SmtUpdateAction.",
+                    input_index
+                ),
             }
             .into());
         }
+    };
+
+    let mut leaves = Vec::new();
+    for item in update_action.updates().into_iter() {
+        let mut key = [0u8; 32];
+        key.copy_from_slice(item.key().as_slice());
+
+        cc_assert!(
+            keys.remove(&key),
+            TickError::InvalidDepositRegistryUpdate {
+                msg: format!("The key {} is not a deposit of the TickCells.", hex::encode(key)),
+            }
+        );
+        cc_assert!(
+            u8::from(item.packed_values()) == smt::SMT_UPDATE_INSERT,
+            TickError::InvalidDepositRegistryUpdate {
+                msg: format!("The key {} should be inserted.", hex::encode(key)),
+            }
+        );
+
+        leaves.push((key, smt::SMT_VALUE_NOT_EXISTING, smt::SMT_VALUE_EXISTING));
+    }
+    cc_assert!(
+        keys.is_empty(),
+        TickError::InvalidDepositRegistryUpdate {
+            msg: format!("There are {} deposits not inserted.", keys.len()),
+        }
+    );
+
+    verifiers::smt::verify_smt_update(&old_root, &new_root, leaves, &update_action.proof().raw_data())?;
+
+    Ok(())
+}
+
+fn verify_mint_request_value(tick: &TickCellData) -> Result<(), Box<dyn AsI8>> {
+    let quota_type = match mint_quota_cell_type()? {
+        Some(script) => script,
+        None => return Ok(()),
+    };
+
+    debug!("Verify if the value of the mint request is in the MintQuota.");
+
+    let quota_cells = util::find_cells_by_script(ScriptType::Type, quota_type.as_reader(), Source::CellDep)?;
+    verifiers::basic::verify_cell_dep_number("MintQuotaCell", &quota_cells, 1)?;
+
+    let data = high_level::load_cell_data(quota_cells[0], Source::CellDep).map_err(TickError::from)?;
+    let quotas = parse_mint_quotas(&data)?;
+    if let Some(quota) = quotas
+        .iter()
+        .find(|quota| quota.is_owned_by(&tick.token_id, &tick.merchant))
+    {
+        verify_mint_request_value_limit(quota, tick.value)?;
+    }
+
+    Ok(())
+}
+
+fn verify_mint_request_value_limit(quota: &MintQuotaData, value: u128) -> Result<(), Box<dyn AsI8>> {
+    cc_assert!(
+        quota.max_request_value == 0 || value <= quota.max_request_value,
+        TickError::MintRequestValueExceeded {
+            value,
+            max: quota.max_request_value
+        }
+    );
+
+    Ok(())
+}
+
+fn verify_mint_quota_update(ticks: &[TickCellData], minted_amount_map: &XudtAmountMap) -> Result<(), Box<dyn AsI8>> {
+    let quota_type = match mint_quota_cell_type()? {
+        Some(script) => script,
+        None => return Ok(()),
+    };
+
+    debug!("Verify if the MintQuotaCell is updated with all minted values.");
+
+    let (input_quota_cells, output_quota_cells) =
+        util::find_cells_by_script_in_inputs_and_outputs(ScriptType::Type, quota_type.as_reader())?;
+    verifiers::basic::verify_cell_number_range(
+        "MintQuotaCell",
+        &input_quota_cells,
+        (Ordering::Equal, 1),
+        &output_quota_cells,
+        (Ordering::Equal, 1),
+    )?;
+
+    let input_index = input_quota_cells[0];
+    let output_index = output_quota_cells[0];
+    verifiers::basic::verify_cell_consistent_with_exception(
+        "MintQuotaCell",
+        input_index,
+        output_index,
+        vec![CellField::Capacity, CellField::Data],
+    )?;
+
+    let data = high_level::load_cell_data(input_index, Source::Input).map_err(TickError::from)?;
+    let old_quotas = parse_mint_quotas(&data)?;
+    let data = high_level::load_cell_data(output_index, Source::Output).map_err(TickError::from)?;
+    let new_quotas = parse_mint_quotas(&data)?;
+
+    cc_assert!(
+        old_quotas.len() == new_quotas.len(),
+        TickError::InvalidMintQuotaUpdate {
+            msg: "The number of MintQuotas should not be changed.".to_string(),
+        }
+    );
+
+    for tick in ticks.iter() {
+        if let Some(quota) = old_quotas
+            .iter()
+            .find(|quota| quota.is_owned_by(&tick.token_id, &tick.merchant))
+        {
+            verify_mint_request_value_limit(quota, tick.value)?;
+        }
+    }
+
+    let since = high_level::load_input_since(input_index, Source::Input).map_err(TickError::from)?;
+    for (i, (old, new)) in old_quotas.iter().zip(new_quotas.iter()).enumerate() {
+        cc_assert!(
+            old.is_owned_by(&new.token_id, &new.merchant)
+                && old.max_request_value == new.max_request_value
+                && old.cap == new.cap
+                && old.period == new.period,
+            TickError::InvalidMintQuotaUpdate {
+                msg: format!("The limits of MintQuotas[{}] should not be changed.", i),
+            }
+        );
+
+        let key = (old.token_id.clone(), old.merchant.as_slice().to_vec());
+        let minted = minted_amount_map.get(&key).copied().unwrap_or(0);
+        let expected_minted = if new.period_start == old.period_start {
+            checked_add_amount(&old.token_id, old.minted, minted)?
+        } else {
+            cc_assert!(
+                old.period > 0 && new.period_start >= old.period_start.saturating_add(old.period),
+                TickError::InvalidMintQuotaUpdate {
+                    msg: format!("The period of MintQuotas[{}] is not over yet.", i),
+                }
+            );
+            cc_assert!(
+                since_util::is_since_reached(since, since_util::build_absolute_timestamp_since(new.period_start))?,
+                TickError::InvalidMintQuotaUpdate {
+                    msg: format!(
+                        "The since of the MintQuotaCell should prove that the period_start of MintQuotas[{}] is reached.",
+                        i
+                    ),
+                }
+            );
+
+            minted
+        };
+
+        cc_assert!(
+            new.minted == expected_minted,
+            TickError::InvalidMintQuotaUpdate {
+                msg: format!("The minted of MintQuotas[{}] should be {}.", i, expected_minted),
+            }
+        );
+        cc_assert!(
+            new.cap == 0 || new.minted <= new.cap,
+            TickError::MintQuotaExceeded {
+                token_id: hex::encode(&new.token_id),
+                merchant: format!("{}", new.merchant),
+                minted: new.minted,
+                cap: new.cap,
+            }
+        );
     }
 
     Ok(())
@@ -147,7 +536,7 @@ fn reject_mint(
     input_xudt_cells: Vec<usize>,
     output_xudt_cells: Vec<usize>,
 ) -> Result<(), Box<dyn AsI8>> {
-    verifiers::permission::verify_input_has_custodian_lock(1)?;
+    let (custodian_index, _) = verifiers::permission::verify_input_has_custodian_lock()?;
     verifiers::basic::verify_cell_number_and_position("TickCell", &input_tick_cells, &[0], &output_tick_cells, &[])?;
     verifiers::basic::verify_cell_number_range(
         "TickCell",
@@ -167,6 +556,14 @@ fn reject_mint(
         }
     );
 
+    verify_tick_receipts(
+        &input_tick_cells,
+        slice::from_ref(&tick),
+        custodian_index,
+        TickOutcome::Rejected,
+        None,
+    )?;
+
     Ok(())
 }
 
@@ -176,8 +573,8 @@ fn confirm_burn(
     input_xudt_cells: Vec<usize>,
     output_xudt_cells: Vec<usize>,
 ) -> Result<(), Box<dyn AsI8>> {
-    verifiers::permission::verify_input_has_custodian_lock(1)?;
-    verifiers::basic::verify_cell_number_and_position("TickCell", &input_tick_cells, &[0], &output_tick_cells, &[])?;
+    verify_batched_tick_cells_position(&input_tick_cells, &output_tick_cells)?;
+    let (custodian_index, _) = verifiers::permission::verify_input_has_custodian_lock()?;
     verifiers::basic::verify_cell_number_range(
         "XudtCell",
         &input_xudt_cells,
@@ -187,55 +584,80 @@ fn confirm_burn(
         (Ordering::Equal, 0),
     )?;
 
-    let tick = load_tick_data(0, Source::Input)?;
+    let ticks = load_tick_data_list(&input_tick_cells, Source::Input, TickType::Burn)?;
+    verify_ticks_before_deadline(&input_tick_cells, &ticks)?;
+    for tick in ticks.iter() {
+        check_token_status(&tick.token_id)?;
+    }
+    verify_escrow_cells_consumed(&input_tick_cells, &ticks, &input_xudt_cells, &output_xudt_cells)?;
+    let payouts = load_burn_payouts(&input_tick_cells, &ticks)?;
+    verify_tick_receipts(
+        &input_tick_cells,
+        &ticks,
+        custodian_index,
+        TickOutcome::Confirmed,
+        Some(&payouts),
+    )?;
 
-    cc_assert!(
-        tick.type_ == TickType::Burn,
-        TickError::InvalidTickType {
-            current: tick.type_.to_string(),
-            expected: TickType::Burn.to_string()
+    // The output_xudt_cells could be empty or have some change cells, so they are compared with inputs token by token.
+    let input_xudt_amount_map = collect_xudt_map(input_xudt_cells, Source::Input)?;
+    let output_xudt_amount_map = collect_xudt_map(output_xudt_cells, Source::Output)?;
+    let input_amount_map = sum_by_token_id(&input_xudt_amount_map)?;
+    let output_amount_map = sum_by_token_id(&output_xudt_amount_map)?;
+    let tick_value_map = sum_by_token_id(&collect_tick_value_map(&ticks)?)?;
+
+    // The fee is transferred to the fee lock in addition to the burned value, so it is a part of the outputs.
+    if let Some(fee) = fee_config()? {
+        let mut fee_amount_map: BTreeMap<&Vec<u8>, u128> = BTreeMap::new();
+        for tick in ticks.iter() {
+            let fee_amount = fee_amount_map.entry(&tick.token_id).or_insert(0);
+            *fee_amount = checked_add_amount(&tick.token_id, *fee_amount, fee.calc_fee(tick.value))?;
         }
-    );
 
-    let (input_token_id, input_xudt_amount_map) = collect_xudt_map(input_xudt_cells, Source::Input)?;
+        for (token_id, expected_fee) in fee_amount_map {
+            let key = (token_id.to_owned(), fee.lock.as_slice().to_vec());
+            let input_amount = input_xudt_amount_map.get(&key).copied().unwrap_or(0);
+            let output_amount = output_xudt_amount_map.get(&key).copied().unwrap_or(0);
 
-    cc_assert!(
-        input_token_id == tick.token_id,
-        TickError::XudtCellTokenIdMismatch {
-            expected: hex::encode(&tick.token_id),
-            current: hex::encode(&input_token_id),
+            cc_assert!(
+                output_amount.checked_sub(input_amount) == Some(expected_fee),
+                TickError::XudtTransferError {
+                    target_lock: format!("{}", fee.lock),
+                    token_id: hex::encode(token_id),
+                    amount: expected_fee,
+                }
+            );
         }
-    );
+    }
 
-    let total_input_amount: u128 = input_xudt_amount_map.iter().map(|(_, v)| v.to_owned()).sum();
+    let token_ids: BTreeSet<&Vec<u8>> = input_amount_map
+        .keys()
+        .chain(output_amount_map.keys())
+        .chain(tick_value_map.keys())
+        .collect();
+    for token_id in token_ids {
+        let total_input_amount = input_amount_map.get(token_id).copied().unwrap_or(0);
+        let total_output_amount = output_amount_map.get(token_id).copied().unwrap_or(0);
+        let total_tick_value = tick_value_map.get(token_id).copied().unwrap_or(0);
 
-    let total_output_amount = if output_xudt_cells.len() == 0 {
-        0u128
-    } else {
-        let (output_token_id, output_xudt_amount_map) = collect_xudt_map(output_xudt_cells, Source::Output)?;
+        debug!(
+            "The token({}) has input {}, output {} and tick value {}",
+            hex::encode(token_id),
+            total_input_amount,
+            total_output_amount,
+            total_tick_value
+        );
 
         cc_assert!(
-            output_token_id == tick.token_id,
-            TickError::XudtCellTokenIdMismatch {
-                expected: hex::encode(&tick.token_id),
-                current: hex::encode(&output_token_id),
+            total_input_amount == checked_add_amount(token_id, total_output_amount, total_tick_value)?,
+            TickError::BurnedXudtAmountNotMatch {
+                burned: total_input_amount.saturating_sub(total_output_amount),
+                expected: total_tick_value
             }
         );
+    }
 
-        output_xudt_amount_map.iter().map(|(_, v)| v.to_owned()).sum()
-    };
-
-    cc_assert!(
-        total_input_amount == total_output_amount + tick.value,
-        TickError::BurnedXudtAmountNotMatch {
-            burned: if total_input_amount > total_output_amount {
-                total_input_amount - total_output_amount
-            } else {
-                0
-            },
-            expected: tick.value
-        }
-    );
+    verify_xudt_supply_update(&tick_value_map, TickType::Burn)?;
 
     Ok(())
 }
@@ -246,7 +668,7 @@ fn reject_burn(
     input_xudt_cells: Vec<usize>,
     output_xudt_cells: Vec<usize>,
 ) -> Result<(), Box<dyn AsI8>> {
-    verifiers::permission::verify_input_has_custodian_lock(1)?;
+    let (custodian_index, _) = verifiers::permission::verify_input_has_custodian_lock()?;
     verifiers::basic::verify_cell_number_and_position("TickCell", &input_tick_cells, &[0], &output_tick_cells, &[])?;
     verifiers::basic::verify_cell_number_range(
         "XudtCell",
@@ -266,112 +688,990 @@ fn reject_burn(
         }
     );
 
-    let merchant_lock = tick.merchant.as_slice();
-    let (token_id, xudt_amount_map) = collect_xudt_map(output_xudt_cells, Source::Output)?;
+    verify_escrow_cells_consumed(
+        &input_tick_cells,
+        slice::from_ref(&tick),
+        &input_xudt_cells,
+        &output_xudt_cells,
+    )?;
+    verify_tick_receipts(
+        &input_tick_cells,
+        slice::from_ref(&tick),
+        custodian_index,
+        TickOutcome::Rejected,
+        None,
+    )?;
+
+    let input_xudt_map = collect_xudt_map(input_xudt_cells, Source::Input)?;
+    let output_xudt_map = collect_xudt_map(output_xudt_cells, Source::Output)?;
+    verify_xudt_amount_conserved(&input_xudt_map, &output_xudt_map)?;
 
+    // The merchant must get back exactly the tick.value, and no other lock is allowed to gain any tokens.
+    let merchant_key = (tick.token_id.clone(), tick.merchant.as_slice().to_vec());
+    let merchant_input = input_xudt_map.get(&merchant_key).copied().unwrap_or(0);
+    let merchant_output = output_xudt_map.get(&merchant_key).copied().unwrap_or(0);
     cc_assert!(
-        token_id == tick.token_id,
-        TickError::XudtCellTokenIdMismatch {
-            expected: hex::encode(&tick.token_id),
-            current: hex::encode(&token_id),
+        merchant_output.checked_sub(merchant_input) == Some(tick.value),
+        TickError::ReturnedXudtAmountNotMatch {
+            returned: merchant_output.saturating_sub(merchant_input),
+            expected: tick.value,
         }
     );
 
-    match xudt_amount_map.get(merchant_lock) {
-        Some(amount) => {
-            cc_assert!(
-                *amount >= tick.value,
-                TickError::XudtTransferError {
-                    target_lock: format!("{}", tick.merchant),
-                    token_id: hex::encode(&tick.token_id),
-                    amount: tick.value,
-                }
-            );
-        }
-        None => {
-            return Err(TickError::XudtTransferError {
-                target_lock: format!("{}", tick.merchant),
-                token_id: hex::encode(&tick.token_id),
-                amount: tick.value,
+    for (key, output_amount) in output_xudt_map.iter().filter(|(key, _)| *key != &merchant_key) {
+        let input_amount = input_xudt_map.get(key).copied().unwrap_or(0);
+        cc_assert!(
+            *output_amount <= input_amount,
+            TickError::UnexpectedXudtTransfer {
+                target_lock: hex::encode(&key.1),
+                token_id: hex::encode(&key.0),
+                amount: output_amount - input_amount,
             }
-            .into());
-        }
+        );
     }
 
     Ok(())
 }
 
-fn verify_if_tick_data_valid(expected_type: TickType, tick: &TickCellData) -> Result<(), Box<dyn AsI8>> {
-    debug!("Verify if the fields of TickCell is valid.");
+// The merchant can withdraw its pending request at any time, the deadline only stops the custodians.
+fn cancel(
+    input_tick_cells: Vec<usize>,
+    output_tick_cells: Vec<usize>,
+    input_xudt_cells: Vec<usize>,
+    output_xudt_cells: Vec<usize>,
+    tick_type: TickType,
+) -> Result<(), Box<dyn AsI8>> {
+    verifiers::basic::verify_cell_number_and_position("TickCell", &input_tick_cells, &[0], &output_tick_cells, &[])?;
+
+    let tick = load_tick_data(0, Source::Input)?;
 
     cc_assert!(
-        tick.type_ == expected_type,
+        tick.type_ == tick_type,
         TickError::InvalidTickType {
             current: tick.type_.to_string(),
-            expected: TickType::Burn.to_string()
+            expected: tick_type.to_string()
         }
     );
-    cc_assert!(tick.token_id.len() == TOKEN_ID_SIZE, TickError::InvalidTickTokenIdSize);
-    cc_assert!(tick.value > 0, TickError::TickValueCanNotBeZero);
 
-    Ok(())
-}
+    verify_if_tick_belong_to_merchant(&tick.merchant)?;
 
-fn load_tick_data(index: usize, source: Source) -> Result<TickCellData, Box<dyn AsI8>> {
-    let data = high_level::load_cell_data(index, source).map_err(TickError::from)?;
-    let tick_data = parse_tick(&data)?;
+    verify_escrow_returned_to_merchant(&input_tick_cells, &tick, input_xudt_cells, output_xudt_cells)?;
 
-    Ok(tick_data)
+    Ok(())
 }
 
-fn verify_if_tick_belong_to_merchant(
-    tick_merchant: &Script,
-    index: usize,
-    source: Source,
+// The owner can close any pending TickCell, nothing is minted and everything is returned to the merchant.
+fn force_close_tick(
+    input_tick_cells: Vec<usize>,
+    output_tick_cells: Vec<usize>,
+    input_xudt_cells: Vec<usize>,
+    output_xudt_cells: Vec<usize>,
 ) -> Result<(), Box<dyn AsI8>> {
-    let sign_merchant = high_level::load_cell_lock(index, source).map_err(TickError::from)?;
+    verifiers::basic::verify_cell_number_and_position("TickCell", &input_tick_cells, &[0], &output_tick_cells, &[])?;
+    verifiers::permission::verify_inputs_have_owner_lock()?;
+
+    let tick = load_tick_data(0, Source::Input)?;
+
+    let mut expected_capacity = high_level::load_cell_capacity(0, Source::Input).map_err(TickError::from)?;
+    for &index in input_xudt_cells.iter() {
+        expected_capacity += high_level::load_cell_capacity(index, Source::Input).map_err(TickError::from)?;
+    }
+
+    let input_xudt_map = collect_xudt_map(input_xudt_cells.clone(), Source::Input)?;
+    let output_xudt_map = collect_xudt_map(output_xudt_cells.clone(), Source::Output)?;
+    verify_escrow_returned_to_merchant(&input_tick_cells, &tick, input_xudt_cells, output_xudt_cells)?;
+    verify_xudt_amount_conserved(&input_xudt_map, &output_xudt_map)?;
+
+    debug!("Verify if the capacity of the TickCell is returned to the merchant.");
+
+    // The cells of the merchant in inputs are deducted, so its own change can not be taken as the returned capacity.
+    let mut merchant_input_capacity = 0u64;
+    for index in util::find_cells_by_script(ScriptType::Lock, tick.merchant.as_reader(), Source::Input)? {
+        merchant_input_capacity += high_level::load_cell_capacity(index, Source::Input).map_err(TickError::from)?;
+    }
+    let mut merchant_output_capacity = 0u64;
+    for index in util::find_cells_by_script(ScriptType::Lock, tick.merchant.as_reader(), Source::Output)? {
+        merchant_output_capacity += high_level::load_cell_capacity(index, Source::Output).map_err(TickError::from)?;
+    }
+    let returned_capacity = merchant_output_capacity.saturating_sub(merchant_input_capacity);
 
     cc_assert!(
-        util::is_entity_eq(tick_merchant, &sign_merchant),
-        TickError::InvalidTickMerchantLock {
-            lock: format!("{}", sign_merchant)
+        returned_capacity >= expected_capacity,
+        TickError::TickCapacityNotReturned {
+            lock: format!("{}", tick.merchant),
+            expected: expected_capacity,
+            current: returned_capacity
         }
     );
 
     Ok(())
 }
 
-fn collect_xudt_map(
-    xudt_cells: Vec<usize>,
-    source: Source,
-) -> Result<(Vec<u8>, BTreeMap<Vec<u8>, u128>), Box<dyn AsI8>> {
-    debug!("Collecting information of XudtCells ...");
-
-    let mut token_id = vec![];
-    let mut has_found_args = vec![];
-    let mut xudt_amount_map = BTreeMap::new();
-    for index in xudt_cells {
-        let type_script = match high_level::load_cell_type(index, source).map_err(TickError::from)? {
-            Some(data) => data,
-            None => unreachable!(),
+fn verify_escrow_returned_to_merchant(
+    input_tick_cells: &[usize],
+    tick: &TickCellData,
+    input_xudt_cells: Vec<usize>,
+    output_xudt_cells: Vec<usize>,
+) -> Result<(), Box<dyn AsI8>> {
+    if tick.is_escrowed() {
+        verifiers::basic::verify_cell_number_range(
+            "XudtCell",
+            &input_xudt_cells,
+            (Ordering::Equal, 1),
+            &output_xudt_cells,
+            (Ordering::Greater, 0),
+        )?;
+        verify_escrow_cells_consumed(
+            input_tick_cells,
+            slice::from_ref(tick),
+            &input_xudt_cells,
+            &output_xudt_cells,
+        )?;
+
+        let key = (tick.token_id.clone(), tick.merchant.as_slice().to_vec());
+        let xudt_amount_map = collect_xudt_map(output_xudt_cells, Source::Output)?;
+        cc_assert!(
+            xudt_amount_map.get(&key) == Some(&tick.value),
+            TickError::XudtTransferError {
+                target_lock: format!("{}", tick.merchant),
+                token_id: hex::encode(&tick.token_id),
+                amount: tick.value,
+            }
+        );
+    } else {
+        verifiers::basic::verify_cell_number_range(
+            "XudtCell",
+            &input_xudt_cells,
+            (Ordering::Equal, 0),
+            &output_xudt_cells,
+            (Ordering::Equal, 0),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn verify_xudt_supply_update(
+    token_value_map: &BTreeMap<Vec<u8>, u128>,
+    tick_type: TickType,
+) -> Result<BTreeMap<Vec<u8>, u128>, Box<dyn AsI8>> {
+    let mut supply_map = BTreeMap::new();
+    let supply_type = match xudt_supply_cell_type()? {
+        Some(script) => script,
+        None => return Ok(supply_map),
+    };
+
+    debug!("Verify if the XudtSupplyCell is updated with all {} values.", tick_type);
+
+    let (input_supply_cells, output_supply_cells) =
+        util::find_cells_by_script_in_inputs_and_outputs(ScriptType::Type, supply_type.as_reader())?;
+    verifiers::basic::verify_cell_number_range(
+        "XudtSupplyCell",
+        &input_supply_cells,
+        (Ordering::Equal, 1),
+        &output_supply_cells,
+        (Ordering::Equal, 1),
+    )?;
+
+    let input_index = input_supply_cells[0];
+    let output_index = output_supply_cells[0];
+    verifiers::basic::verify_cell_consistent_with_exception(
+        "XudtSupplyCell",
+        input_index,
+        output_index,
+        vec![CellField::Capacity, CellField::Data],
+    )?;
+
+    // The XudtSupplyCell is locked by this script, so it can only be updated by the transactions verified here.
+    let lock = high_level::load_cell_lock(input_index, Source::Input).map_err(TickError::from)?;
+    let self_script = high_level::load_script().map_err(TickError::from)?;
+    cc_assert!(
+        lock.as_slice() == self_script.as_slice(),
+        TickError::InvalidXudtSupplyUpdate {
+            token_id: String::new(),
+            msg: String::from("The XudtSupplyCell should be locked by the tick-cell-type script."),
+        }
+    );
+
+    let data = high_level::load_cell_data(input_index, Source::Input).map_err(TickError::from)?;
+    let old_supplies = parse_xudt_supplies(&data)?;
+    let data = high_level::load_cell_data(output_index, Source::Output).map_err(TickError::from)?;
+    let new_supplies = parse_xudt_supplies(&data)?;
+
+    cc_assert!(
+        old_supplies.len() == new_supplies.len(),
+        TickError::InvalidXudtSupplyUpdate {
+            token_id: String::new(),
+            msg: "The number of XudtSupplies should not be changed.".to_string(),
+        }
+    );
+
+    for (i, (old, new)) in old_supplies.iter().zip(new_supplies.iter()).enumerate() {
+        cc_assert!(
+            old.token_id == new.token_id && old.max_supply == new.max_supply,
+            TickError::InvalidXudtSupplyUpdate {
+                token_id: hex::encode(&old.token_id),
+                msg: format!(
+                    "The token_id and max_supply of XudtSupplies[{}] should not be changed.",
+                    i
+                ),
+            }
+        );
+        cc_assert!(
+            !supply_map.contains_key(&old.token_id),
+            TickError::InvalidXudtSupplyUpdate {
+                token_id: hex::encode(&old.token_id),
+                msg: String::from("There should be only one XudtSupply for each token."),
+            }
+        );
+
+        let value = token_value_map.get(&old.token_id).copied().unwrap_or(0);
+        let expected_supply = match tick_type {
+            TickType::Mint => old.supply.checked_add(value),
+            TickType::Burn => old.supply.checked_sub(value),
+        };
+        let expected_supply = match expected_supply {
+            Some(supply) => supply,
+            None => {
+                return Err(TickError::InvalidXudtSupplyUpdate {
+                    token_id: hex::encode(&old.token_id),
+                    msg: format!(
+                        "Overflow when updating the supply {} by the {} value {}.",
+                        old.supply, tick_type, value
+                    ),
+                }
+                .into());
+            }
         };
-        let args = type_script.as_reader().args().raw_data();
 
-        debug!("The current XudtCell.type.args is: {}", hex::encode(args));
+        cc_assert!(
+            new.supply == expected_supply,
+            TickError::InvalidXudtSupplyUpdate {
+                token_id: hex::encode(&old.token_id),
+                msg: format!("The supply of XudtSupplies[{}] should be {}.", i, expected_supply),
+            }
+        );
+
+        if let Some(max_supply) = new.max_supply {
+            cc_assert!(
+                new.supply <= max_supply,
+                TickError::XudtSupplyExceeded {
+                    token_id: hex::encode(&old.token_id),
+                    supply: new.supply,
+                    max_supply,
+                }
+            );
+        }
+
+        supply_map.insert(old.token_id.clone(), new.supply);
+    }
+
+    for token_id in token_value_map.keys() {
+        cc_assert!(
+            supply_map.contains_key(token_id),
+            TickError::InvalidXudtSupplyUpdate {
+                token_id: hex::encode(token_id),
+                msg: String::from("The XudtSupply of the token is not found in the XudtSupplyCell."),
+            }
+        );
+    }
+
+    Ok(supply_map)
+}
+
+// Scripts can not get the current time, so the freshness is measured against the latest block in header_deps of the
+// confirming transaction.
+fn verify_reserve_attestations(
+    ticks: &[TickCellData],
+    supply_map: &BTreeMap<Vec<u8>, u128>,
+) -> Result<(), Box<dyn AsI8>> {
+    let config = match reserve_attestation_config()? {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+
+    debug!("Verify if the minted tokens are backed by the attested reserves.");
+
+    // Anyone can create cells with the attestation type, so only the ones with the custodian lock are trusted.
+    let custodian_lock = verifiers::permission::load_custodian_lock()?;
+    let mut attestations: BTreeMap<Vec<u8>, ReserveAttestationData> = BTreeMap::new();
+    for index in util::find_cells_by_script(ScriptType::Type, config.type_.as_reader(), Source::CellDep)? {
+        let lock = high_level::load_cell_lock(index, Source::CellDep).map_err(TickError::from)?;
+        if !util::is_entity_eq(&custodian_lock, &lock) {
+            continue;
+        }
+
+        let data = high_level::load_cell_data(index, Source::CellDep).map_err(TickError::from)?;
+        let attestation = parse_reserve_attestation(&data)?;
+        cc_assert!(
+            !attestations.contains_key(&attestation.coin_type),
+            TickError::InvalidReserveAttestation {
+                coin_type: hex::encode(&attestation.coin_type),
+                msg: String::from("There should be only one attestation for each coin_type."),
+            }
+        );
+        attestations.insert(attestation.coin_type.clone(), attestation);
+    }
+
+    let mut tx_timestamp = None;
+    let mut i = 0;
+    loop {
+        let header = match high_level::load_header(i, Source::HeaderDep) {
+            Ok(header) => header,
+            Err(SysError::IndexOutOfBound) => break,
+            Err(err) => return Err(TickError::from(err).into()),
+        };
+        // The timestamp of block header is in milliseconds.
+        let timestamp = Unpack::<u64>::unpack(&header.raw().timestamp()) / 1000;
+        tx_timestamp = tx_timestamp.max(Some(timestamp));
+        i += 1;
+    }
+
+    for tick in ticks.iter() {
+        let attestation = match attestations.get(&tick.coin_type) {
+            Some(attestation) => attestation,
+            None => {
+                return Err(TickError::InvalidReserveAttestation {
+                    coin_type: hex::encode(&tick.coin_type),
+                    msg: String::from("The attestation with the custodian lock is required in cell_deps."),
+                }
+                .into());
+            }
+        };
+
+        let tx_timestamp = match tx_timestamp {
+            Some(timestamp) => timestamp,
+            None => {
+                return Err(TickError::InvalidReserveAttestation {
+                    coin_type: hex::encode(&tick.coin_type),
+                    msg: String::from("A recent block header is required in header_deps to check the freshness."),
+                }
+                .into());
+            }
+        };
+        cc_assert!(
+            attestation.timestamp.saturating_add(config.max_age) >= tx_timestamp,
+            TickError::ReserveAttestationStale {
+                coin_type: hex::encode(&tick.coin_type),
+                timestamp: attestation.timestamp,
+                tx_timestamp,
+            }
+        );
 
-        if has_found_args.is_empty() {
-            has_found_args = args.to_vec();
+        let supply = match supply_map.get(&tick.token_id) {
+            Some(&supply) => supply,
+            None => {
+                return Err(TickError::InvalidReserveAttestation {
+                    coin_type: hex::encode(&tick.coin_type),
+                    msg: format!(
+                        "The supply of token {} should be tracked by the XudtSupplyCell.",
+                        hex::encode(&tick.token_id)
+                    ),
+                }
+                .into());
+            }
+        };
+        cc_assert!(
+            supply <= attestation.reserve,
+            TickError::ReserveExceeded {
+                coin_type: hex::encode(&tick.coin_type),
+                supply,
+                reserve: attestation.reserve,
+            }
+        );
+    }
+
+    Ok(())
+}
+
+fn verify_tick_receipts(
+    tick_cells: &[usize],
+    ticks: &[TickCellData],
+    custodian_index: usize,
+    outcome: TickOutcome,
+    settlement_tx_hashes: Option<&[Vec<u8>]>,
+) -> Result<(), Box<dyn AsI8>> {
+    let receipt_type = match tick_receipt_type()? {
+        Some(script) => script,
+        None => return Ok(()),
+    };
+
+    debug!("Verify if the TickReceipt cells are created for all TickCells.");
+
+    let receipt_cells = util::find_cells_by_script(ScriptType::Type, receipt_type.as_reader(), Source::Output)?;
+    cc_assert!(
+        receipt_cells.len() == ticks.len(),
+        TickError::InvalidTickReceipt {
+            index: receipt_cells.len(),
+            msg: format!(
+                "There should be {} TickReceipt cells, but {} found.",
+                ticks.len(),
+                receipt_cells.len()
+            ),
+        }
+    );
+
+    let custodian_lock_hash =
+        high_level::load_cell_lock_hash(custodian_index, Source::Input).map_err(TickError::from)?;
+    for (i, ((&tick_index, tick), &index)) in tick_cells
+        .iter()
+        .zip(ticks.iter())
+        .zip(receipt_cells.iter())
+        .enumerate()
+    {
+        let lock = high_level::load_cell_lock(index, Source::Output).map_err(TickError::from)?;
+        cc_assert!(
+            util::is_entity_eq(&lock, &tick.merchant),
+            TickError::InvalidTickReceipt {
+                index,
+                msg: String::from("The TickReceipt cell should be owned by the merchant."),
+            }
+        );
+
+        let data = high_level::load_cell_data(index, Source::Output).map_err(TickError::from)?;
+        let (_, receipt) = data_parser::tick_receipt_cell::parse_data(&data)?;
+
+        let tick_data = high_level::load_cell_data(tick_index, Source::Input).map_err(TickError::from)?;
+        cc_assert!(
+            receipt.tick().raw_data().as_ref() == tick_data.as_slice(),
+            TickError::InvalidTickReceipt {
+                index,
+                msg: String::from("The TickReceipt.tick should be the data of the TickCell."),
+            }
+        );
+        cc_assert!(
+            u8::from(receipt.outcome()) == outcome as u8,
+            TickError::InvalidTickReceipt {
+                index,
+                msg: format!("The TickReceipt.outcome should be {}.", outcome),
+            }
+        );
+        cc_assert!(
+            receipt.custodian_lock_hash().as_slice() == custodian_lock_hash.as_slice(),
+            TickError::InvalidTickReceipt {
+                index,
+                msg: String::from("The TickReceipt.custodian_lock_hash should be the hash of the custodian lock."),
+            }
+        );
+
+        let settlement_tx_hash = receipt.settlement_tx_hash().raw_data();
+        let expected_tx_hash = settlement_tx_hashes
+            .map(|hashes| hashes[i].as_slice())
+            .unwrap_or_default();
+        cc_assert!(
+            settlement_tx_hash.as_ref() == expected_tx_hash,
+            TickError::InvalidTickReceipt {
+                index,
+                msg: format!(
+                    "The TickReceipt.settlement_tx_hash should be 0x{}.",
+                    hex::encode(expected_tx_hash)
+                ),
+            }
+        );
+    }
+
+    Ok(())
+}
+
+fn load_burn_payouts(tick_cells: &[usize], ticks: &[TickCellData]) -> Result<Vec<Vec<u8>>, Box<dyn AsI8>> {
+    debug!("Load the BurnPayouts from the witnesses of TickCells.");
+
+    let mut payouts = Vec::new();
+    for (&index, tick) in tick_cells.iter().zip(ticks.iter()) {
+        let witness_args = high_level::load_witness_args(index, Source::Input).map_err(TickError::from)?;
+        let payout = match witness_args.input_type().to_opt() {
+            Some(bytes) => {
+                BurnPayout::from_compatible_slice(&bytes.raw_data()).map_err(|_| TickError::InvalidBurnPayout {
+                    index,
+                    msg: String::from("Parse input_type to BurnPayout failed."),
+                })?
+            }
+            None => {
+                return Err(TickError::InvalidBurnPayout {
+                    index,
+                    msg: String::from("The input_type should contain BurnPayout."),
+                }
+                .into());
+            }
+        };
+
+        cc_assert!(
+            payout.coin_type().raw_data().as_ref() == tick.coin_type.as_slice(),
+            TickError::InvalidBurnPayout {
+                index,
+                msg: format!("The BurnPayout.coin_type should be 0x{}.", hex::encode(&tick.coin_type)),
+            }
+        );
+
+        let tx_hash = payout.tx_hash().raw_data().to_vec();
+        let expected_size = Chain::from_coin_type(&tick.coin_type)?.tx_hash_size();
+        cc_assert!(
+            tx_hash.len() == expected_size,
+            TickError::InvalidBurnPayout {
+                index,
+                msg: format!(
+                    "The size of BurnPayout.tx_hash should be {}, but {} found.",
+                    expected_size,
+                    tx_hash.len()
+                ),
+            }
+        );
+
+        payouts.push(tx_hash);
+    }
+
+    Ok(payouts)
+}
+
+fn is_running_as_lock(self_script: &Script) -> Result<bool, Box<dyn AsI8>> {
+    match high_level::load_cell_lock(0, Source::GroupInput) {
+        Ok(lock) => Ok(lock.as_slice() == self_script.as_slice()),
+        Err(SysError::IndexOutOfBound) => Ok(false),
+        Err(err) => Err(TickError::from(err).into()),
+    }
+}
+
+fn verify_locked_cells_action(action: Action) -> Result<(), Box<dyn AsI8>> {
+    let registry_type = deposit_registry_cell_type()?;
+    let supply_type = xudt_supply_cell_type()?;
+
+    let mut i = 0;
+    loop {
+        let type_ = match high_level::load_cell_type(i, Source::GroupInput) {
+            Ok(type_) => type_,
+            Err(SysError::IndexOutOfBound) => break,
+            Err(err) => return Err(TickError::from(err).into()),
+        };
+
+        if type_
+            .as_ref()
+            .is_some_and(|type_| type_.as_slice() == registry_type.as_slice())
+        {
+            cc_assert!(
+                matches!(action, Action::ConfirmMint | Action::ConfirmMintPartial),
+                TickError::DepositRegistryCellSpentByInvalidAction {
+                    action: action.to_string()
+                }
+            );
+        } else if type_
+            .as_ref()
+            .zip(supply_type.as_ref())
+            .is_some_and(|(type_, supply_type)| type_.as_slice() == supply_type.as_slice())
+        {
+            cc_assert!(
+                matches!(
+                    action,
+                    Action::ConfirmMint | Action::ConfirmMintPartial | Action::ConfirmBurn
+                ),
+                TickError::XudtSupplyCellSpentByInvalidAction {
+                    action: action.to_string()
+                }
+            );
         } else {
             cc_assert!(
-                has_found_args == args,
-                TickError::MultipleKindOfXudtFound {
+                matches!(
+                    action,
+                    Action::ConfirmBurn | Action::RejectBurn | Action::CancelBurn | Action::ForceCloseTick
+                ),
+                TickError::EscrowCellSpentByInvalidAction {
+                    action: action.to_string()
+                }
+            );
+        }
+
+        i += 1;
+    }
+
+    Ok(())
+}
+
+fn find_escrow_cells(xudt_cells: &[usize], source: Source) -> Result<Vec<usize>, Box<dyn AsI8>> {
+    let escrow_lock = high_level::load_script().map_err(TickError::from)?;
+
+    let mut escrow_cells = Vec::new();
+    for &index in xudt_cells {
+        let lock = high_level::load_cell_lock(index, source).map_err(TickError::from)?;
+        if lock.as_slice() == escrow_lock.as_slice() {
+            escrow_cells.push(index);
+        }
+    }
+
+    Ok(escrow_cells)
+}
+
+fn verify_escrow_cell_created(tick: &TickCellData, output_xudt_cells: &[usize]) -> Result<(), Box<dyn AsI8>> {
+    debug!("Verify if the escrow XudtCell is created with the TickCell.");
+
+    let escrow_cells = find_escrow_cells(output_xudt_cells, Source::Output)?;
+    for &index in escrow_cells.iter() {
+        cc_assert!(
+            index == 1,
+            TickError::UnexpectedEscrowCell {
+                index,
+                source: format!("{:?}", Source::Output)
+            }
+        );
+    }
+    cc_assert!(
+        !escrow_cells.is_empty(),
+        TickError::EscrowCellMissing {
+            index: 0,
+            source: format!("{:?}", Source::Output)
+        }
+    );
+
+    let escrow_lock = high_level::load_cell_lock(1, Source::Output).map_err(TickError::from)?;
+    let xudt_amount_map = collect_xudt_map(escrow_cells, Source::Output)?;
+    for (token_id, _) in xudt_amount_map.keys() {
+        cc_assert!(
+            *token_id == tick.token_id,
+            TickError::XudtCellTokenIdMismatch {
+                expected: hex::encode(&tick.token_id),
+                current: hex::encode(token_id),
+            }
+        );
+    }
+
+    let amount = xudt_amount_map[&(tick.token_id.clone(), escrow_lock.as_slice().to_vec())];
+    cc_assert!(
+        amount == tick.value,
+        TickError::EscrowedXudtAmountNotMatch {
+            escrowed: amount,
+            expected: tick.value
+        }
+    );
+
+    Ok(())
+}
+
+fn verify_escrow_cells_consumed(
+    tick_cells: &[usize],
+    ticks: &[TickCellData],
+    input_xudt_cells: &[usize],
+    output_xudt_cells: &[usize],
+) -> Result<(), Box<dyn AsI8>> {
+    debug!("Verify if the escrow XudtCells are consumed with their TickCells.");
+
+    if let Some(&index) = find_escrow_cells(output_xudt_cells, Source::Output)?.first() {
+        return Err(TickError::UnexpectedEscrowCell {
+            index,
+            source: format!("{:?}", Source::Output),
+        }
+        .into());
+    }
+
+    let mut escrow_cells = find_escrow_cells(input_xudt_cells, Source::Input)?;
+    for (&index, tick) in tick_cells.iter().zip(ticks.iter()) {
+        if !tick.is_escrowed() {
+            continue;
+        }
+
+        let tick_out_point = high_level::load_input_out_point(index, Source::Input).map_err(TickError::from)?;
+        let tick_out_index: u32 = tick_out_point.index().unpack();
+
+        let mut found = None;
+        for (i, &escrow_index) in escrow_cells.iter().enumerate() {
+            let out_point = high_level::load_input_out_point(escrow_index, Source::Input).map_err(TickError::from)?;
+            let out_index: u32 = out_point.index().unpack();
+            if out_point.tx_hash().as_slice() == tick_out_point.tx_hash().as_slice() && out_index == tick_out_index + 1
+            {
+                found = Some(i);
+                break;
+            }
+        }
+
+        match found {
+            Some(i) => {
+                escrow_cells.remove(i);
+            }
+            None => {
+                return Err(TickError::EscrowCellMissing {
                     index,
-                    source: format!("{:?}", source)
+                    source: format!("{:?}", Source::Input),
                 }
+                .into());
+            }
+        }
+    }
+
+    if let Some(&index) = escrow_cells.first() {
+        return Err(TickError::UnexpectedEscrowCell {
+            index,
+            source: format!("{:?}", Source::Input),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+fn verify_if_tick_data_valid(expected_type: TickType, tick: &TickCellData) -> Result<(), Box<dyn AsI8>> {
+    debug!("Verify if the fields of TickCell is valid.");
+
+    cc_assert!(
+        tick.type_ == expected_type,
+        TickError::InvalidTickType {
+            current: tick.type_.to_string(),
+            expected: expected_type.to_string()
+        }
+    );
+    cc_assert!(
+        tick.escrowed <= 1,
+        TickError::InvalidEscrowedFlag { value: tick.escrowed }
+    );
+    cc_assert!(tick.token_id.len() == TOKEN_ID_SIZE, TickError::InvalidTickTokenIdSize);
+    cc_assert!(tick.value > 0, TickError::TickValueCanNotBeZero);
+    cc_assert!(
+        tick.memo.len() <= MAX_MEMO_SIZE,
+        TickError::TickMemoTooLong {
+            max: MAX_MEMO_SIZE,
+            current: tick.memo.len()
+        }
+    );
+
+    // The formats are only verified when the TickCell is created, so the TickCells created before can still be
+    // consumed.
+    let chain = Chain::from_coin_type(&tick.coin_type)?;
+    // A burn request does not need a tx_hash, since the tokens have not been paid on the foreign chain yet.
+    if tick.type_ == TickType::Mint || !tick.tx_hash.is_empty() {
+        chain.verify_tx_hash(&tick.tx_hash)?;
+    }
+    chain.verify_address(&tick.receipt_addr)?;
+
+    if let Some(deadline) = tick.deadline {
+        since_util::verify_absolute_since(deadline)?;
+    }
+
+    Ok(())
+}
+
+// The since must be absolute and before the deadline, so the transaction can not be committed after the deadline.
+fn verify_ticks_before_deadline(input_tick_cells: &[usize], ticks: &[TickCellData]) -> Result<(), Box<dyn AsI8>> {
+    for (&index, tick) in input_tick_cells.iter().zip(ticks.iter()) {
+        if let Some(deadline) = tick.deadline {
+            let since = high_level::load_input_since(index, Source::Input).map_err(TickError::from)?;
+            cc_assert!(
+                !since_util::is_since_reached(since, deadline)?,
+                TickError::TickDeadlinePassed { deadline, since }
             );
         }
+    }
 
-        token_id = (&args[..32]).to_vec();
+    Ok(())
+}
+
+fn load_tick_data(index: usize, source: Source) -> Result<TickCellData, Box<dyn AsI8>> {
+    let data = high_level::load_cell_data(index, source).map_err(TickError::from)?;
+    let tick_data = parse_tick(&data)?;
+
+    Ok(tick_data)
+}
+
+fn load_tick_data_list(
+    indexes: &[usize],
+    source: Source,
+    expected_type: TickType,
+) -> Result<Vec<TickCellData>, Box<dyn AsI8>> {
+    let mut ticks = Vec::new();
+    let mut nonces = BTreeSet::new();
+    for &index in indexes {
+        let tick = load_tick_data(index, source)?;
+
+        cc_assert!(
+            tick.type_ == expected_type,
+            TickError::InvalidTickType {
+                current: tick.type_.to_string(),
+                expected: expected_type.to_string()
+            }
+        );
+
+        if let Some(nonce) = tick.nonce {
+            cc_assert!(
+                nonces.insert((tick.merchant.as_slice().to_vec(), nonce)),
+                TickError::DuplicatedTickNonce {
+                    merchant: format!("{}", tick.merchant),
+                    nonce
+                }
+            );
+        }
+
+        ticks.push(tick);
+    }
+
+    Ok(ticks)
+}
+
+fn verify_batched_tick_cells_position(
+    input_tick_cells: &[usize],
+    output_tick_cells: &[usize],
+) -> Result<(), Box<dyn AsI8>> {
+    verifiers::basic::verify_cell_number_range(
+        "TickCell",
+        input_tick_cells,
+        (Ordering::Greater, 0),
+        output_tick_cells,
+        (Ordering::Equal, 0),
+    )?;
+
+    let expected_inputs: Vec<usize> = (0..input_tick_cells.len()).collect();
+    verifiers::basic::verify_cell_number_and_position(
+        "TickCell",
+        input_tick_cells,
+        &expected_inputs,
+        output_tick_cells,
+        &[],
+    )?;
+
+    Ok(())
+}
+
+fn collect_tick_value_map(ticks: &[TickCellData]) -> Result<XudtAmountMap, Box<dyn AsI8>> {
+    let mut tick_value_map = BTreeMap::new();
+    for tick in ticks {
+        let key = (tick.token_id.clone(), tick.merchant.as_slice().to_vec());
+        let value = match tick_value_map.get(&key) {
+            Some(prev_value) => checked_add_amount(&tick.token_id, *prev_value, tick.value)?,
+            None => tick.value,
+        };
+
+        tick_value_map.insert(key, value);
+    }
+
+    Ok(tick_value_map)
+}
+
+fn verify_if_tick_belong_to_merchant(tick_merchant: &Script) -> Result<(), Box<dyn AsI8>> {
+    let cells = util::find_cells_by_script(ScriptType::Lock, tick_merchant.as_reader(), Source::Input)?;
+
+    cc_assert!(
+        !cells.is_empty(),
+        TickError::InvalidTickMerchantLock {
+            lock: format!("{}", tick_merchant)
+        }
+    );
+
+    Ok(())
+}
+
+fn collect_mint_amount_map(ticks: &[TickCellData], fee: Option<&FeeConfig>) -> Result<XudtAmountMap, Box<dyn AsI8>> {
+    let mut amount_map = BTreeMap::new();
+    for tick in ticks {
+        let fee_amount = fee.map(|fee| fee.calc_fee(tick.value)).unwrap_or(0);
+        let amount = amount_map
+            .entry((tick.token_id.clone(), tick.merchant.as_slice().to_vec()))
+            .or_insert(0u128);
+        *amount = checked_add_amount(&tick.token_id, *amount, tick.value - fee_amount)?;
+
+        if let Some(fee) = fee {
+            let amount = amount_map
+                .entry((tick.token_id.clone(), fee.lock.as_slice().to_vec()))
+                .or_insert(0u128);
+            *amount = checked_add_amount(&tick.token_id, *amount, fee_amount)?;
+        }
+    }
+
+    Ok(amount_map)
+}
+
+fn verify_xudt_amount(
+    xudt_amount_map: &XudtAmountMap,
+    token_id: &[u8],
+    lock: &Script,
+    expected_amount: u128,
+) -> Result<(), Box<dyn AsI8>> {
+    let amount = xudt_amount_map
+        .get(&(token_id.to_vec(), lock.as_slice().to_vec()))
+        .copied()
+        .unwrap_or(0);
+
+    cc_assert!(
+        amount == expected_amount,
+        TickError::XudtTransferError {
+            target_lock: format!("{}", lock),
+            token_id: hex::encode(token_id),
+            amount: expected_amount,
+        }
+    );
+
+    Ok(())
+}
+
+fn verify_xudt_amount_conserved(
+    input_xudt_map: &XudtAmountMap,
+    output_xudt_map: &XudtAmountMap,
+) -> Result<(), Box<dyn AsI8>> {
+    let input_token_map = sum_by_token_id(input_xudt_map)?;
+    let output_token_map = sum_by_token_id(output_xudt_map)?;
+    let token_ids = input_token_map
+        .keys()
+        .chain(output_token_map.keys())
+        .collect::<BTreeSet<_>>();
+
+    for token_id in token_ids {
+        let input = input_token_map.get(token_id).copied().unwrap_or(0);
+        let output = output_token_map.get(token_id).copied().unwrap_or(0);
+        cc_assert!(
+            input == output,
+            TickError::XudtAmountNotConserved {
+                token_id: hex::encode(token_id),
+                input,
+                output,
+            }
+        );
+    }
+
+    Ok(())
+}
+
+fn sum_by_token_id(amount_map: &XudtAmountMap) -> Result<BTreeMap<Vec<u8>, u128>, Box<dyn AsI8>> {
+    let mut token_amount_map = BTreeMap::new();
+    for ((token_id, _), &amount) in amount_map.iter() {
+        let total = token_amount_map.entry(token_id.to_owned()).or_insert(0u128);
+        *total = checked_add_amount(token_id, *total, amount)?;
+    }
+
+    Ok(token_amount_map)
+}
+
+fn checked_add_amount(token_id: &[u8], a: u128, b: u128) -> Result<u128, Box<dyn AsI8>> {
+    match a.checked_add(b) {
+        Some(amount) => Ok(amount),
+        None => Err(TickError::AmountOverflow {
+            token_id: hex::encode(token_id),
+        }
+        .into()),
+    }
+}
+
+fn collect_xudt_map(xudt_cells: Vec<usize>, source: Source) -> Result<XudtAmountMap, Box<dyn AsI8>> {
+    debug!("Collecting information of XudtCells ...");
+
+    let mut found_args_map: BTreeMap<Vec<u8>, Vec<u8>> = BTreeMap::new();
+    let mut xudt_amount_map = BTreeMap::new();
+    for index in xudt_cells {
+        let type_script = match high_level::load_cell_type(index, source).map_err(TickError::from)? {
+            Some(data) => data,
+            None => unreachable!(),
+        };
+        let args = type_script.as_reader().args().raw_data();
+
+        debug!("The current XudtCell.type.args is: {}", hex::encode(args));
+
+        let token_id = args[..32].to_vec();
+        match found_args_map.get(&token_id) {
+            Some(found_args) => {
+                cc_assert!(
+                    found_args == args,
+                    TickError::MultipleKindOfXudtFound {
+                        index,
+                        source: format!("{:?}", source)
+                    }
+                );
+            }
+            None => {
+                found_args_map.insert(token_id.clone(), args.to_vec());
+            }
+        }
 
         debug!("The xudt token ID is: {}", hex::encode(&token_id));
 
@@ -386,11 +1686,11 @@ fn collect_xudt_map(
         );
 
         let lock_script = high_level::load_cell_lock(index, source).map_err(TickError::from)?;
-        let key = lock_script.as_slice().to_vec();
+        let key = (token_id.clone(), lock_script.as_slice().to_vec());
         let mut amount = u128::from_le_bytes((&data[..16]).try_into().unwrap());
 
         amount = match xudt_amount_map.get(&key) {
-            Some(prev_amount) => *prev_amount + amount,
+            Some(prev_amount) => checked_add_amount(&token_id, *prev_amount, amount)?,
             None => amount,
         };
 
@@ -404,5 +1704,5 @@ fn collect_xudt_map(
         );
     }
 
-    Ok((token_id, xudt_amount_map))
+    Ok(xudt_amount_map)
 }
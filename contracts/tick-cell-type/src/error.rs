@@ -36,7 +36,7 @@ pub enum TickError {
     UnsupportedXudtData { index: usize, source: String },
     #[error("{source}[{index}] The extension script in XudtCell.lock.args is unknown.")]
     UnsupportedXudtExtensionScript { index: usize, source: String },
-    #[error("{source}[{index}] Found multiple kind of XudtCell, but only one kind is supported.")]
+    #[error("{source}[{index}] Found XudtCells of the same token with different type.args.")]
     MultipleKindOfXudtFound { index: usize, source: String },
     #[error("The burned Xudt amount {burned} does not match the expected amount {expected} in TickCell")]
     BurnedXudtAmountNotMatch { burned: u128, expected: u128 },
@@ -46,6 +46,107 @@ pub enum TickError {
         token_id: String,
         amount: u128,
     },
+    #[error("The minted Xudt amount {minted} does not match the expected amount {expected} in TickCell")]
+    MintedXudtAmountNotMatch { minted: u128, expected: u128 },
+    #[error("The deposit is duplicated in TickCells.(coin_type: {coin_type}, tx_hash: {tx_hash})")]
+    DuplicatedDeposit { coin_type: String, tx_hash: String },
+    #[error("The DepositRegistryCell is not updated properly: {msg}")]
+    InvalidDepositRegistryUpdate { msg: String },
+    #[error("The escrow XudtCell can not be spent by the action {action} .")]
+    EscrowCellSpentByInvalidAction { action: String },
+    #[error("Only the TickCell of burn can be escrowed.")]
+    OnlyBurnTickCanBeEscrowed,
+    #[error("{source}[{index}] The escrow XudtCell of the TickCell is not found.")]
+    EscrowCellMissing { index: usize, source: String },
+    #[error("{source}[{index}] The escrow XudtCell is unexpected here.")]
+    UnexpectedEscrowCell { index: usize, source: String },
+    #[error("The escrowed Xudt amount {escrowed} does not match the expected amount {expected} in TickCell")]
+    EscrowedXudtAmountNotMatch { escrowed: u128, expected: u128 },
+    #[error("The value {value} of the mint request exceeds the limit {max} of the merchant.")]
+    MintRequestValueExceeded { value: u128, max: u128 },
+    #[error(
+        "The minted value {minted} of {token_id} exceeds the cap {cap} of the merchant {merchant} in this period."
+    )]
+    MintQuotaExceeded {
+        token_id: String,
+        merchant: String,
+        minted: u128,
+        cap: u128,
+    },
+    #[error("The MintQuotaCell is not updated properly: {msg}")]
+    InvalidMintQuotaUpdate { msg: String },
+    #[error("The TickCell.data.coin_type 0x{coin_type} is not a supported SLIP-44 coin type.")]
+    UnsupportedCoinType { coin_type: String },
+    #[error("The size of TickCell.data.tx_hash is invalid.(expected: {expected}, current: {current})")]
+    InvalidTickTxHashSize { expected: usize, current: usize },
+    #[error("The TickCell.data.receipt_addr {address} is invalid on {chain}: {msg}")]
+    InvalidReceiptAddress {
+        chain: String,
+        address: String,
+        msg: String,
+    },
+    #[error("The size of TickCell.data.memo is too long.(max: {max}, current: {current})")]
+    TickMemoTooLong { max: usize, current: usize },
+    #[error("The TickCell.data.nonce {nonce} is duplicated for the merchant {merchant} .")]
+    DuplicatedTickNonce { merchant: String, nonce: u64 },
+    #[error("The total amount of {token_id} is not conserved.(input: {input}, output: {output})")]
+    XudtAmountNotConserved {
+        token_id: String,
+        input: u128,
+        output: u128,
+    },
+    #[error("The returned Xudt amount {returned} does not match the expected amount {expected} in TickCell")]
+    ReturnedXudtAmountNotMatch { returned: u128, expected: u128 },
+    #[error("There should be no {token_id} token transferred to {target_lock}, but {amount} found.")]
+    UnexpectedXudtTransfer {
+        target_lock: String,
+        token_id: String,
+        amount: u128,
+    },
+    #[error("The confirmed value {confirmed} should be in the range (0, {value}].")]
+    InvalidConfirmedValue { confirmed: u128, value: u128 },
+    #[error("The residual TickCell is invalid: {msg}")]
+    InvalidResidualTickCell { msg: String },
+    #[error("outputs[{index}] The TickReceipt cell is invalid: {msg}")]
+    InvalidTickReceipt { index: usize, msg: String },
+    #[error("witnesses[{index}] The BurnPayout is invalid: {msg}")]
+    InvalidBurnPayout { index: usize, msg: String },
+    #[error("The capacity returned to {lock} should be at least {expected}, but only {current} found.")]
+    TickCapacityNotReturned { lock: String, expected: u64, current: u64 },
+    #[error("The XudtSupplyCell of token {token_id} is updated incorrectly: {msg}")]
+    InvalidXudtSupplyUpdate { token_id: String, msg: String },
+    #[error("The supply {supply} of token {token_id} exceeds the max supply {max_supply}.")]
+    XudtSupplyExceeded {
+        token_id: String,
+        supply: u128,
+        max_supply: u128,
+    },
+    #[error("The reserve attestation of coin_type {coin_type} is invalid: {msg}")]
+    InvalidReserveAttestation { coin_type: String, msg: String },
+    #[error("The reserve attestation of coin_type {coin_type} at {timestamp} is stale for the transaction at {tx_timestamp}.")]
+    ReserveAttestationStale {
+        coin_type: String,
+        timestamp: u64,
+        tx_timestamp: u64,
+    },
+    #[error("The supply {supply} exceeds the attested reserve {reserve} of coin_type {coin_type}.")]
+    ReserveExceeded {
+        coin_type: String,
+        supply: u128,
+        reserve: u128,
+    },
+    #[error(
+        "The TickCell can not be confirmed after its deadline.(deadline: 0x{deadline:016x}, since: 0x{since:016x})"
+    )]
+    TickDeadlinePassed { deadline: u64, since: u64 },
+    #[error("The escrowed flag of TickCell should be 0 or 1, but {value} found.")]
+    InvalidEscrowedFlag { value: u8 },
+    #[error("The amount of token {token_id} overflows.")]
+    AmountOverflow { token_id: String },
+    #[error("The DepositRegistryCell can not be spent by the action {action} .")]
+    DepositRegistryCellSpentByInvalidAction { action: String },
+    #[error("The XudtSupplyCell can not be spent by the action {action} .")]
+    XudtSupplyCellSpentByInvalidAction { action: String },
 }
 
 impl From<SysError> for TickError {
@@ -80,6 +181,40 @@ impl AsI8 for TickError {
             TickError::MultipleKindOfXudtFound { .. } => 14,
             TickError::BurnedXudtAmountNotMatch { .. } => 15,
             TickError::XudtTransferError { .. } => 16,
+            TickError::MintedXudtAmountNotMatch { .. } => 17,
+            TickError::DuplicatedDeposit { .. } => 19,
+            TickError::InvalidDepositRegistryUpdate { .. } => 20,
+            TickError::EscrowCellSpentByInvalidAction { .. } => 21,
+            TickError::OnlyBurnTickCanBeEscrowed => 22,
+            TickError::EscrowCellMissing { .. } => 23,
+            TickError::UnexpectedEscrowCell { .. } => 24,
+            TickError::EscrowedXudtAmountNotMatch { .. } => 25,
+            TickError::MintRequestValueExceeded { .. } => 26,
+            TickError::MintQuotaExceeded { .. } => 27,
+            TickError::InvalidMintQuotaUpdate { .. } => 28,
+            TickError::UnsupportedCoinType { .. } => 29,
+            TickError::InvalidTickTxHashSize { .. } => 30,
+            TickError::InvalidReceiptAddress { .. } => 31,
+            TickError::TickMemoTooLong { .. } => 32,
+            TickError::DuplicatedTickNonce { .. } => 33,
+            TickError::XudtAmountNotConserved { .. } => 34,
+            TickError::ReturnedXudtAmountNotMatch { .. } => 35,
+            TickError::UnexpectedXudtTransfer { .. } => 36,
+            TickError::InvalidConfirmedValue { .. } => 37,
+            TickError::InvalidResidualTickCell { .. } => 38,
+            TickError::InvalidTickReceipt { .. } => 39,
+            TickError::InvalidBurnPayout { .. } => 40,
+            TickError::TickCapacityNotReturned { .. } => 41,
+            TickError::InvalidXudtSupplyUpdate { .. } => 42,
+            TickError::XudtSupplyExceeded { .. } => 43,
+            TickError::InvalidReserveAttestation { .. } => 44,
+            TickError::ReserveAttestationStale { .. } => 45,
+            TickError::ReserveExceeded { .. } => 46,
+            TickError::TickDeadlinePassed { .. } => 47,
+            TickError::InvalidEscrowedFlag { .. } => 48,
+            TickError::AmountOverflow { .. } => 49,
+            TickError::DepositRegistryCellSpentByInvalidAction { .. } => 50,
+            TickError::XudtSupplyCellSpentByInvalidAction { .. } => 51,
         }
     }
 }
@@ -8,6 +8,7 @@
 #![cfg_attr(not(test), no_main)]
 
 // define modules
+mod chain;
 mod entry;
 mod error;
 mod parser;
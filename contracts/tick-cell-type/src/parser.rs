@@ -2,9 +2,11 @@ use alloc::boxed::Box;
 use alloc::vec::Vec;
 
 use contract_core::data_parser;
+use contract_core::data_parser::tick_cell::VersionedTick;
 use contract_core::error::AsI8;
 use types::constants::TickType;
-use types::packed::Script;
+use types::packed::{MintQuota, ReserveAttestation, Script, XudtSupply};
+use types::prelude::Entity;
 
 use crate::error::TickError;
 
@@ -17,24 +19,69 @@ pub struct TickCellData {
     pub coin_type: Vec<u8>,
     pub tx_hash: Vec<u8>,
     pub receipt_addr: Vec<u8>,
+    // The nonce only exists since version 1.
+    pub nonce: Option<u64>,
+    pub memo: Vec<u8>,
+    pub deadline: Option<u64>,
+    // The raw escrowed flag, only 0 and 1 are valid.
+    pub escrowed: u8,
+}
+
+impl TickCellData {
+    pub fn is_escrowed(&self) -> bool {
+        self.escrowed == 1
+    }
+
+    /// Return true if the other TickCell is the same request as this one, only the value may be different.
+    pub fn is_same_request(&self, other: &TickCellData) -> bool {
+        self.version == other.version
+            && self.type_ == other.type_
+            && self.token_id == other.token_id
+            && self.merchant.as_slice() == other.merchant.as_slice()
+            && self.coin_type == other.coin_type
+            && self.tx_hash == other.tx_hash
+            && self.receipt_addr == other.receipt_addr
+            && self.nonce == other.nonce
+            && self.memo == other.memo
+            && self.deadline == other.deadline
+            && self.escrowed == other.escrowed
+    }
 }
 
 pub fn parse_tick(data: &[u8]) -> Result<TickCellData, Box<dyn AsI8>> {
     let (version, tick) = data_parser::tick_cell::parse_data(data)?;
 
-    let type_ = tick.tick_type().as_slice()[0];
+    macro_rules! parse_common_fields {
+        ($tick:expr) => {
+            (
+                $tick.tick_type().as_slice()[0],
+                $tick.token_id().raw_data().to_vec(),
+                u128::from($tick.value()),
+                $tick.merchant(),
+                $tick.coin_type().raw_data().to_vec(),
+                $tick.tx_hash().raw_data().to_vec(),
+                $tick.receipt_addr().raw_data().to_vec(),
+            )
+        };
+    }
+
+    let (fields, nonce, memo, deadline, escrowed) = match tick {
+        VersionedTick::V0(tick) => (parse_common_fields!(tick), None, Vec::new(), None, 0),
+        VersionedTick::V1(tick) => (
+            parse_common_fields!(tick),
+            Some(u64::from(tick.nonce())),
+            tick.memo().raw_data().to_vec(),
+            tick.deadline().to_opt().map(u64::from),
+            tick.escrowed().as_slice()[0],
+        ),
+    };
+    let (type_, token_id, value, merchant, coin_type, tx_hash, receipt_addr) = fields;
+
     let type_ = match TickType::try_from(type_) {
         Ok(type_) => type_,
         Err(_) => return Err(Box::new(TickError::UnsupportedTickType(type_))),
     };
 
-    let token_id = tick.token_id().raw_data().to_vec();
-    let value = u128::from(tick.value());
-    let merchant = tick.merchant();
-    let coin_type = tick.coin_type().raw_data().to_vec();
-    let tx_hash = tick.tx_hash().raw_data().to_vec();
-    let receipt_addr = tick.receipt_addr().raw_data().to_vec();
-
     Ok(TickCellData {
         version,
         type_,
@@ -44,5 +91,91 @@ pub fn parse_tick(data: &[u8]) -> Result<TickCellData, Box<dyn AsI8>> {
         tx_hash,
         receipt_addr,
         value,
+        nonce,
+        memo,
+        deadline,
+        escrowed,
     })
 }
+
+pub struct MintQuotaData {
+    pub token_id: Vec<u8>,
+    pub merchant: Script,
+    pub max_request_value: u128,
+    pub cap: u128,
+    pub period: u64,
+    pub period_start: u64,
+    pub minted: u128,
+}
+
+impl MintQuotaData {
+    pub fn is_owned_by(&self, token_id: &[u8], merchant: &Script) -> bool {
+        self.token_id == token_id && self.merchant.as_slice() == merchant.as_slice()
+    }
+}
+
+impl From<MintQuota> for MintQuotaData {
+    fn from(quota: MintQuota) -> Self {
+        Self {
+            token_id: quota.token_id().raw_data().to_vec(),
+            merchant: quota.merchant(),
+            max_request_value: u128::from(quota.max_request_value()),
+            cap: u128::from(quota.cap()),
+            period: u64::from(quota.period()),
+            period_start: u64::from(quota.period_start()),
+            minted: u128::from(quota.minted()),
+        }
+    }
+}
+
+pub fn parse_mint_quotas(data: &[u8]) -> Result<Vec<MintQuotaData>, Box<dyn AsI8>> {
+    let (_, quotas) = data_parser::mint_quota_cell::parse_data(data)?;
+
+    Ok(quotas.into_iter().map(MintQuotaData::from).collect())
+}
+
+pub struct XudtSupplyData {
+    pub token_id: Vec<u8>,
+    pub supply: u128,
+    pub max_supply: Option<u128>,
+}
+
+impl From<XudtSupply> for XudtSupplyData {
+    fn from(supply: XudtSupply) -> Self {
+        Self {
+            token_id: supply.token_id().raw_data().to_vec(),
+            supply: u128::from(supply.supply()),
+            max_supply: supply.max_supply().to_opt().map(u128::from),
+        }
+    }
+}
+
+pub fn parse_xudt_supplies(data: &[u8]) -> Result<Vec<XudtSupplyData>, Box<dyn AsI8>> {
+    let (_, supplies) = data_parser::xudt_supply_cell::parse_data(data)?;
+
+    Ok(supplies.into_iter().map(XudtSupplyData::from).collect())
+}
+
+pub struct ReserveAttestationData {
+    pub coin_type: Vec<u8>,
+    pub reserve: u128,
+    pub block_height: u64,
+    pub timestamp: u64,
+}
+
+impl From<ReserveAttestation> for ReserveAttestationData {
+    fn from(attestation: ReserveAttestation) -> Self {
+        Self {
+            coin_type: attestation.coin_type().raw_data().to_vec(),
+            reserve: u128::from(attestation.reserve()),
+            block_height: u64::from(attestation.block_height()),
+            timestamp: u64::from(attestation.timestamp()),
+        }
+    }
+}
+
+pub fn parse_reserve_attestation(data: &[u8]) -> Result<ReserveAttestationData, Box<dyn AsI8>> {
+    let (_, attestation) = data_parser::reserve_attestation_cell::parse_data(data)?;
+
+    Ok(ReserveAttestationData::from(attestation))
+}
@@ -7,12 +7,12 @@ use core::convert::TryFrom;
 use ckb_std::ckb_constants::Source;
 use ckb_std::ckb_types::core::ScriptHashType;
 use ckb_std::high_level;
-use types::constants::{config_cell_type_id, ConfigKey, SystemStatus};
-use types::packed::{Byte, Byte32, Script};
+use types::constants::{config_cell_type_id, Action, ConfigKey, SystemStatus, TOKEN_ID_SIZE};
+use types::packed::{Byte, Byte32, Bytes, Script};
 use types::prelude::{Builder, Entity};
 
 use super::error::CoreError;
-use crate::constants::ScriptType;
+use crate::constants::{ScriptType, TYPE_ID_CODE_HASH};
 use crate::data_parser::config_cell;
 use crate::util;
 
@@ -38,6 +38,24 @@ pub struct Config {
     pub xudt_owner_type_id: Vec<u8>,
     pub xudt_owner_type_args: Vec<u8>,
     pub xudt_info_cell_out_point: Vec<u8>,
+    pub deposit_registry_cell_type_args: Vec<u8>,
+    pub mint_quota_cell_type_args: Vec<u8>,
+    pub fee_lock: Vec<u8>,
+    pub fee_rate: Vec<u8>,
+    pub fee_min: Vec<u8>,
+    pub fee_max: Vec<u8>,
+    pub tick_receipt_type: Vec<u8>,
+    pub request_mint_status: SystemStatus,
+    pub request_burn_status: SystemStatus,
+    pub confirm_mint_status: SystemStatus,
+    pub confirm_burn_status: SystemStatus,
+    pub governance_status: SystemStatus,
+    pub frozen_token_ids: Vec<u8>,
+    pub reserve_attestation_type: Vec<u8>,
+    pub reserve_attestation_max_age: Vec<u8>,
+    pub custodian_cosign_status: SystemStatus,
+    pub xudt_supply_cell_type_args: Vec<u8>,
+    pub custodian_proposal_delay: Vec<u8>,
 }
 
 impl Default for Config {
@@ -62,6 +80,24 @@ impl Default for Config {
             xudt_owner_type_id: vec![0u8; 32],
             xudt_owner_type_args: vec![0u8; 32],
             xudt_info_cell_out_point: vec![0u8; 33],
+            deposit_registry_cell_type_args: vec![],
+            mint_quota_cell_type_args: vec![],
+            fee_lock: vec![],
+            fee_rate: vec![],
+            fee_min: vec![],
+            fee_max: vec![],
+            tick_receipt_type: vec![],
+            request_mint_status: SystemStatus::On,
+            request_burn_status: SystemStatus::On,
+            confirm_mint_status: SystemStatus::On,
+            confirm_burn_status: SystemStatus::On,
+            governance_status: SystemStatus::On,
+            frozen_token_ids: vec![],
+            reserve_attestation_type: vec![],
+            reserve_attestation_max_age: vec![],
+            custodian_cosign_status: SystemStatus::Off,
+            xudt_supply_cell_type_args: vec![],
+            custodian_proposal_delay: DEFAULT_CUSTODIAN_PROPOSAL_DELAY.to_le_bytes().to_vec(),
         }
     }
 }
@@ -88,11 +124,7 @@ impl Config {
         for (key, value) in configs {
             match key {
                 ConfigKey::SystemStatus => {
-                    let status = SystemStatus::try_from(value[0]).map_err(|_| CoreError::ParseCellDataFailed {
-                        cell_name: String::from("ConfigCell"),
-                        msg: format!("Can not parse value to SystemStatus."),
-                    })?;
-                    self.system_status = status;
+                    self.system_status = parse_system_status(key, &value)?;
                 }
                 ConfigKey::GovernanceMemberCellTypeId => {
                     self.governance_member_cell_type_id = value;
@@ -139,6 +171,60 @@ impl Config {
                 ConfigKey::XudtInfoCellTypeOutPoint => {
                     self.xudt_info_cell_out_point = value;
                 }
+                ConfigKey::DepositRegistryCellTypeArgs => {
+                    self.deposit_registry_cell_type_args = value;
+                }
+                ConfigKey::MintQuotaCellTypeArgs => {
+                    self.mint_quota_cell_type_args = value;
+                }
+                ConfigKey::FeeLock => {
+                    self.fee_lock = value;
+                }
+                ConfigKey::FeeRate => {
+                    self.fee_rate = value;
+                }
+                ConfigKey::FeeMin => {
+                    self.fee_min = value;
+                }
+                ConfigKey::FeeMax => {
+                    self.fee_max = value;
+                }
+                ConfigKey::TickReceiptType => {
+                    self.tick_receipt_type = value;
+                }
+                ConfigKey::RequestMintStatus => {
+                    self.request_mint_status = parse_system_status(key, &value)?;
+                }
+                ConfigKey::RequestBurnStatus => {
+                    self.request_burn_status = parse_system_status(key, &value)?;
+                }
+                ConfigKey::ConfirmMintStatus => {
+                    self.confirm_mint_status = parse_system_status(key, &value)?;
+                }
+                ConfigKey::ConfirmBurnStatus => {
+                    self.confirm_burn_status = parse_system_status(key, &value)?;
+                }
+                ConfigKey::GovernanceStatus => {
+                    self.governance_status = parse_system_status(key, &value)?;
+                }
+                ConfigKey::FrozenTokenIds => {
+                    self.frozen_token_ids = value;
+                }
+                ConfigKey::ReserveAttestationType => {
+                    self.reserve_attestation_type = value;
+                }
+                ConfigKey::ReserveAttestationMaxAge => {
+                    self.reserve_attestation_max_age = value;
+                }
+                ConfigKey::CustodianCosignStatus => {
+                    self.custodian_cosign_status = parse_system_status(key, &value)?;
+                }
+                ConfigKey::XudtSupplyCellTypeArgs => {
+                    self.xudt_supply_cell_type_args = value;
+                }
+                ConfigKey::CustodianProposalDelay => {
+                    self.custodian_proposal_delay = value;
+                }
             }
         }
 
@@ -146,6 +232,16 @@ impl Config {
     }
 }
 
+fn parse_system_status(key: ConfigKey, value: &[u8]) -> Result<SystemStatus, CoreError> {
+    value
+        .first()
+        .and_then(|status| SystemStatus::try_from(*status).ok())
+        .ok_or_else(|| CoreError::ParseCellDataFailed {
+            cell_name: String::from("ConfigCell"),
+            msg: format!("Can not parse value of {} to SystemStatus.", key),
+        })
+}
+
 //check if the system is on
 pub fn check_system_status() -> Result<(), CoreError> {
     let config = Config::get_instance();
@@ -185,9 +281,98 @@ pub fn get_config_by_key(key: ConfigKey) -> Result<Vec<u8>, CoreError> {
         ConfigKey::XudtOwnerTypeId => Ok(config.xudt_owner_type_id.clone()),
         ConfigKey::XudtOwnerTypeArgs => Ok(config.xudt_owner_type_args.clone()),
         ConfigKey::XudtInfoCellTypeOutPoint => Ok(config.xudt_info_cell_out_point.clone()),
+        ConfigKey::DepositRegistryCellTypeArgs => Ok(config.deposit_registry_cell_type_args.clone()),
+        ConfigKey::MintQuotaCellTypeArgs => Ok(config.mint_quota_cell_type_args.clone()),
+        ConfigKey::FeeLock => Ok(config.fee_lock.clone()),
+        ConfigKey::FeeRate => Ok(config.fee_rate.clone()),
+        ConfigKey::FeeMin => Ok(config.fee_min.clone()),
+        ConfigKey::FeeMax => Ok(config.fee_max.clone()),
+        ConfigKey::TickReceiptType => Ok(config.tick_receipt_type.clone()),
+        ConfigKey::RequestMintStatus => Ok(vec![config.request_mint_status as u8]),
+        ConfigKey::RequestBurnStatus => Ok(vec![config.request_burn_status as u8]),
+        ConfigKey::ConfirmMintStatus => Ok(vec![config.confirm_mint_status as u8]),
+        ConfigKey::ConfirmBurnStatus => Ok(vec![config.confirm_burn_status as u8]),
+        ConfigKey::GovernanceStatus => Ok(vec![config.governance_status as u8]),
+        ConfigKey::FrozenTokenIds => Ok(config.frozen_token_ids.clone()),
+        ConfigKey::ReserveAttestationType => Ok(config.reserve_attestation_type.clone()),
+        ConfigKey::ReserveAttestationMaxAge => Ok(config.reserve_attestation_max_age.clone()),
+        ConfigKey::CustodianCosignStatus => Ok(vec![config.custodian_cosign_status as u8]),
+        ConfigKey::XudtSupplyCellTypeArgs => Ok(config.xudt_supply_cell_type_args.clone()),
+        ConfigKey::CustodianProposalDelay => Ok(config.custodian_proposal_delay.clone()),
     }
 }
 
+pub fn check_action_status(action: Action) -> Result<(), CoreError> {
+    let key = match action {
+        Action::RequestMint => ConfigKey::RequestMintStatus,
+        Action::RequestBurn => ConfigKey::RequestBurnStatus,
+        Action::ConfirmMint | Action::ConfirmMintPartial => ConfigKey::ConfirmMintStatus,
+        Action::ConfirmBurn => ConfigKey::ConfirmBurnStatus,
+        Action::InitGovernance
+        | Action::UpdateOwner
+        | Action::UpdateCustodians
+        | Action::UpdateMerchants
+        | Action::ProposeCustodians
+        | Action::CancelCustodianProposal => ConfigKey::GovernanceStatus,
+        _ => return Ok(()),
+    };
+
+    let status = get_config_by_key(key)?;
+    cc_assert!(
+        status[0] == SystemStatus::On as u8,
+        CoreError::ActionPaused {
+            action: action.to_string()
+        }
+    );
+
+    Ok(())
+}
+
+pub fn is_custodian_cosign_required() -> Result<bool, CoreError> {
+    let status = get_config_by_key(ConfigKey::CustodianCosignStatus)?;
+
+    Ok(status[0] == SystemStatus::On as u8)
+}
+
+// About one day.
+pub const DEFAULT_CUSTODIAN_PROPOSAL_DELAY: u64 = 6;
+
+pub fn custodian_proposal_delay() -> Result<u64, CoreError> {
+    let delay = get_config_by_key(ConfigKey::CustodianProposalDelay)?;
+    match delay.as_slice().try_into() {
+        Ok(bytes) => Ok(u64::from_le_bytes(bytes)),
+        Err(_) => Err(CoreError::ParseCellDataFailed {
+            cell_name: String::from("ConfigCell"),
+            msg: format!("The {} should be a u64 in LE.", ConfigKey::CustodianProposalDelay),
+        }),
+    }
+}
+
+// The token IDs are concatenated together.
+pub fn check_token_status(token_id: &[u8]) -> Result<(), CoreError> {
+    let frozen_token_ids = get_config_by_key(ConfigKey::FrozenTokenIds)?;
+    cc_assert!(
+        frozen_token_ids.len() % TOKEN_ID_SIZE == 0,
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("ConfigCell"),
+            msg: format!(
+                "The {} should be a list of {} bytes token IDs.",
+                ConfigKey::FrozenTokenIds,
+                TOKEN_ID_SIZE
+            ),
+        }
+    );
+
+    cc_assert!(
+        !frozen_token_ids.chunks(TOKEN_ID_SIZE).any(|id| id == token_id),
+        CoreError::TokenFrozen {
+            token_id: hex::encode(token_id)
+        }
+    );
+
+    Ok(())
+}
+
 pub fn always_success_lock() -> Result<Script, CoreError> {
     let code_hash = get_config_by_key(ConfigKey::AlwaysSuccessTypeId)?;
 
@@ -204,3 +389,171 @@ pub fn omni_lock_type_id() -> Result<Vec<u8>, CoreError> {
 pub fn governance_member_cell_type_id() -> Result<Vec<u8>, CoreError> {
     get_config_by_key(ConfigKey::GovernanceMemberCellTypeId)
 }
+
+pub fn deposit_registry_cell_type() -> Result<Script, CoreError> {
+    let args = get_config_by_key(ConfigKey::DepositRegistryCellTypeArgs)?;
+    cc_assert!(
+        !args.is_empty(),
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("ConfigCell"),
+            msg: format!("The {} is required.", ConfigKey::DepositRegistryCellTypeArgs),
+        }
+    );
+
+    Ok(Script::new_builder()
+        .code_hash(Byte32::from(TYPE_ID_CODE_HASH))
+        .hash_type(Byte::new(ScriptHashType::Type.into()))
+        .args(Bytes::from(args))
+        .build())
+}
+
+pub fn mint_quota_cell_type() -> Result<Option<Script>, CoreError> {
+    let args = get_config_by_key(ConfigKey::MintQuotaCellTypeArgs)?;
+    if args.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        Script::new_builder()
+            .code_hash(Byte32::from(TYPE_ID_CODE_HASH))
+            .hash_type(Byte::new(ScriptHashType::Type.into()))
+            .args(Bytes::from(args))
+            .build(),
+    ))
+}
+
+pub fn xudt_supply_cell_type() -> Result<Option<Script>, CoreError> {
+    let args = get_config_by_key(ConfigKey::XudtSupplyCellTypeArgs)?;
+    if args.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        Script::new_builder()
+            .code_hash(Byte32::from(TYPE_ID_CODE_HASH))
+            .hash_type(Byte::new(ScriptHashType::Type.into()))
+            .args(Bytes::from(args))
+            .build(),
+    ))
+}
+
+pub const FEE_RATE_DENOMINATOR: u128 = 10000;
+
+#[derive(Debug)]
+pub struct FeeConfig {
+    pub lock: Script,
+    // The fee rate in basis points.
+    pub rate: u128,
+    pub min: u128,
+    pub max: u128,
+}
+
+impl FeeConfig {
+    pub fn calc_fee(&self, value: u128) -> u128 {
+        // Split the value to avoid overflow of value * rate.
+        let fee =
+            value / FEE_RATE_DENOMINATOR * self.rate + value % FEE_RATE_DENOMINATOR * self.rate / FEE_RATE_DENOMINATOR;
+        fee.max(self.min).min(self.max).min(value)
+    }
+}
+
+fn parse_fee_amount(key: ConfigKey, value: &[u8], default: u128) -> Result<u128, CoreError> {
+    if value.is_empty() {
+        return Ok(default);
+    }
+
+    let bytes: [u8; 16] = value.try_into().map_err(|_| CoreError::ParseCellDataFailed {
+        cell_name: String::from("ConfigCell"),
+        msg: format!("The {} should be a u128 in LE.", key),
+    })?;
+
+    Ok(u128::from_le_bytes(bytes))
+}
+
+// The FeeRate is in basis points, the FeeMin and FeeMax are optional.
+pub fn fee_config() -> Result<Option<FeeConfig>, CoreError> {
+    let lock = get_config_by_key(ConfigKey::FeeLock)?;
+    if lock.is_empty() {
+        return Ok(None);
+    }
+
+    let lock = Script::from_compatible_slice(&lock).map_err(|_| CoreError::ParseCellDataFailed {
+        cell_name: String::from("ConfigCell"),
+        msg: format!("The {} should be a molecule encoded Script.", ConfigKey::FeeLock),
+    })?;
+
+    let rate = get_config_by_key(ConfigKey::FeeRate)?;
+    let rate = match rate.as_slice().try_into() {
+        Ok(bytes) => u16::from_le_bytes(bytes) as u128,
+        Err(_) => {
+            return Err(CoreError::ParseCellDataFailed {
+                cell_name: String::from("ConfigCell"),
+                msg: format!("The {} should be a u16 in LE.", ConfigKey::FeeRate),
+            });
+        }
+    };
+    let min = parse_fee_amount(ConfigKey::FeeMin, &get_config_by_key(ConfigKey::FeeMin)?, 0)?;
+    let max = parse_fee_amount(ConfigKey::FeeMax, &get_config_by_key(ConfigKey::FeeMax)?, u128::MAX)?;
+
+    cc_assert!(
+        rate <= FEE_RATE_DENOMINATOR && min <= max,
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("ConfigCell"),
+            msg: format!("The fee config is invalid.(rate: {}, min: {}, max: {})", rate, min, max),
+        }
+    );
+
+    Ok(Some(FeeConfig { lock, rate, min, max }))
+}
+
+pub fn tick_receipt_type() -> Result<Option<Script>, CoreError> {
+    let type_ = get_config_by_key(ConfigKey::TickReceiptType)?;
+    if type_.is_empty() {
+        return Ok(None);
+    }
+
+    let type_ = Script::from_compatible_slice(&type_).map_err(|_| CoreError::ParseCellDataFailed {
+        cell_name: String::from("ConfigCell"),
+        msg: format!(
+            "The {} should be a molecule encoded Script.",
+            ConfigKey::TickReceiptType
+        ),
+    })?;
+
+    Ok(Some(type_))
+}
+
+#[derive(Debug)]
+pub struct ReserveAttestationConfig {
+    pub type_: Script,
+    // The max age of an attestation in seconds.
+    pub max_age: u64,
+}
+
+pub fn reserve_attestation_config() -> Result<Option<ReserveAttestationConfig>, CoreError> {
+    let type_ = get_config_by_key(ConfigKey::ReserveAttestationType)?;
+    if type_.is_empty() {
+        return Ok(None);
+    }
+
+    let type_ = Script::from_compatible_slice(&type_).map_err(|_| CoreError::ParseCellDataFailed {
+        cell_name: String::from("ConfigCell"),
+        msg: format!(
+            "The {} should be a molecule encoded Script.",
+            ConfigKey::ReserveAttestationType
+        ),
+    })?;
+
+    let max_age = get_config_by_key(ConfigKey::ReserveAttestationMaxAge)?;
+    let max_age = match max_age.as_slice().try_into() {
+        Ok(bytes) => u64::from_le_bytes(bytes),
+        Err(_) => {
+            return Err(CoreError::ParseCellDataFailed {
+                cell_name: String::from("ConfigCell"),
+                msg: format!("The {} should be a u64 in LE.", ConfigKey::ReserveAttestationMaxAge),
+            });
+        }
+    };
+
+    Ok(Some(ReserveAttestationConfig { type_, max_age }))
+}
@@ -0,0 +1,36 @@
+use alloc::string::{String, ToString};
+
+use types::packed::Byte32;
+use types::prelude::Entity;
+
+use crate::error::CoreError;
+
+/// The data of DepositRegistryCell is a version byte followed by the SMT root of all consumed deposits.
+pub fn parse_data(data: &[u8]) -> Result<(u8, [u8; 32]), CoreError> {
+    cc_assert!(
+        data.len() == 33,
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("DepositRegistryCell"),
+            msg: "The data should be 1 byte version and 32 bytes SMT root.".to_string(),
+        }
+    );
+
+    let version = data[0];
+    let root = match version {
+        0 => Byte32::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+            cell_name: String::from("DepositRegistryCell"),
+            msg: "Parse slice to Byte32 failed.".to_string(),
+        })?,
+        _ => {
+            return Err(CoreError::ParseCellDataVersionFailed {
+                version,
+                cell_name: String::from("DepositRegistryCell"),
+            });
+        }
+    };
+
+    let mut ret = [0u8; 32];
+    ret.copy_from_slice(root.as_slice());
+
+    Ok((version, ret))
+}
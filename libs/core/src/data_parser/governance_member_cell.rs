@@ -3,7 +3,7 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 use types::constants::GovernanceMemberRole;
-use types::packed::GovernanceMembers;
+use types::packed::{CustodianProposal, GovernanceMembers, GovernanceMembersV1};
 use types::prelude::Entity;
 
 use crate::error::CoreError;
@@ -36,7 +36,12 @@ pub fn parse_type_args(args: &[u8]) -> Result<(GovernanceMemberRole, Vec<u8>), C
     Ok((role, cell_id))
 }
 
-pub fn parse_data(data: &[u8]) -> Result<(u8, GovernanceMembers), CoreError> {
+pub enum VersionedGovernanceMembers {
+    V0(GovernanceMembers),
+    V1(GovernanceMembersV1),
+}
+
+pub fn parse_data(data: &[u8]) -> Result<(u8, VersionedGovernanceMembers), CoreError> {
     cc_assert!(
         data.len() > 2,
         CoreError::ParseCellDataFailed {
@@ -46,14 +51,22 @@ pub fn parse_data(data: &[u8]) -> Result<(u8, GovernanceMembers), CoreError> {
     );
 
     let version = data[0];
-    let governance_members: GovernanceMembers;
-    match version {
+    let governance_members = match version {
         0 => {
-            governance_members =
+            let members =
                 GovernanceMembers::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
                     cell_name: String::from("GovernanceMemberCell"),
                     msg: format!("Parse slice to GovernanceMembers failed."),
                 })?;
+            VersionedGovernanceMembers::V0(members)
+        }
+        1 => {
+            let members =
+                GovernanceMembersV1::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+                    cell_name: String::from("GovernanceMemberCell"),
+                    msg: format!("Parse slice to GovernanceMembersV1 failed."),
+                })?;
+            VersionedGovernanceMembers::V1(members)
         }
         _ => {
             return Err(CoreError::ParseCellDataVersionFailed {
@@ -61,7 +74,34 @@ pub fn parse_data(data: &[u8]) -> Result<(u8, GovernanceMembers), CoreError> {
                 cell_name: String::from("GovernanceMemberCell"),
             });
         }
-    }
+    };
 
     Ok((version, governance_members))
 }
+
+/// The data of GovernanceMemberCell(custodian_proposal) is a version byte followed by the CustodianProposal.
+pub fn parse_proposal_data(data: &[u8]) -> Result<(u8, CustodianProposal), CoreError> {
+    cc_assert!(
+        data.len() > 2,
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("GovernanceMemberCell(custodian_proposal)"),
+            msg: "The data is too short.".to_string(),
+        }
+    );
+
+    let version = data[0];
+    let proposal = match version {
+        0 => CustodianProposal::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+            cell_name: String::from("GovernanceMemberCell(custodian_proposal)"),
+            msg: "Parse slice to CustodianProposal failed.".to_string(),
+        })?,
+        _ => {
+            return Err(CoreError::ParseCellDataVersionFailed {
+                version,
+                cell_name: String::from("GovernanceMemberCell(custodian_proposal)"),
+            });
+        }
+    };
+
+    Ok((version, proposal))
+}
@@ -0,0 +1,33 @@
+use alloc::string::{String, ToString};
+
+use types::packed::MintQuotas;
+use types::prelude::Entity;
+
+use crate::error::CoreError;
+
+/// The data of MintQuotaCell is a version byte followed by the molecule encoded MintQuotas.
+pub fn parse_data(data: &[u8]) -> Result<(u8, MintQuotas), CoreError> {
+    cc_assert!(
+        !data.is_empty(),
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("MintQuotaCell"),
+            msg: "The data is too short.".to_string(),
+        }
+    );
+
+    let version = data[0];
+    let quotas = match version {
+        0 => MintQuotas::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+            cell_name: String::from("MintQuotaCell"),
+            msg: "Parse slice to MintQuotas failed.".to_string(),
+        })?,
+        _ => {
+            return Err(CoreError::ParseCellDataVersionFailed {
+                version,
+                cell_name: String::from("MintQuotaCell"),
+            });
+        }
+    };
+
+    Ok((version, quotas))
+}
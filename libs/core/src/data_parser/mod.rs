@@ -1,3 +1,8 @@
 pub mod config_cell;
+pub mod deposit_registry_cell;
 pub mod governance_member_cell;
+pub mod mint_quota_cell;
+pub mod reserve_attestation_cell;
 pub mod tick_cell;
+pub mod tick_receipt_cell;
+pub mod xudt_supply_cell;
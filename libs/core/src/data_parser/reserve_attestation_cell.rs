@@ -0,0 +1,33 @@
+use alloc::string::{String, ToString};
+
+use types::packed::ReserveAttestation;
+use types::prelude::Entity;
+
+use crate::error::CoreError;
+
+/// The data of ReserveAttestationCell is a version byte followed by the molecule encoded ReserveAttestation.
+pub fn parse_data(data: &[u8]) -> Result<(u8, ReserveAttestation), CoreError> {
+    cc_assert!(
+        !data.is_empty(),
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("ReserveAttestationCell"),
+            msg: "The data is too short.".to_string(),
+        }
+    );
+
+    let version = data[0];
+    let attestation = match version {
+        0 => ReserveAttestation::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+            cell_name: String::from("ReserveAttestationCell"),
+            msg: "Parse slice to ReserveAttestation failed.".to_string(),
+        })?,
+        _ => {
+            return Err(CoreError::ParseCellDataVersionFailed {
+                version,
+                cell_name: String::from("ReserveAttestationCell"),
+            });
+        }
+    };
+
+    Ok((version, attestation))
+}
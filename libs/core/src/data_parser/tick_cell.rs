@@ -1,12 +1,17 @@
 use alloc::format;
 use alloc::string::{String, ToString};
 
-use types::packed::Tick;
+use types::packed::{Tick, TickV1};
 use types::prelude::Entity;
 
 use crate::error::CoreError;
 
-pub fn parse_data(data: &[u8]) -> Result<(u8, Tick), CoreError> {
+pub enum VersionedTick {
+    V0(Tick),
+    V1(TickV1),
+}
+
+pub fn parse_data(data: &[u8]) -> Result<(u8, VersionedTick), CoreError> {
     cc_assert!(
         data.len() > 2,
         CoreError::ParseCellDataFailed {
@@ -16,13 +21,20 @@ pub fn parse_data(data: &[u8]) -> Result<(u8, Tick), CoreError> {
     );
 
     let version = data[0];
-    let tick: Tick;
-    match version {
+    let tick = match version {
         0 => {
-            tick = Tick::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+            let tick = Tick::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
                 cell_name: String::from("TickCell"),
                 msg: format!("Parse slice to TickCell failed."),
             })?;
+            VersionedTick::V0(tick)
+        }
+        1 => {
+            let tick = TickV1::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+                cell_name: String::from("TickCell"),
+                msg: "Parse slice to TickV1 failed.".to_string(),
+            })?;
+            VersionedTick::V1(tick)
         }
         _ => {
             return Err(CoreError::ParseCellDataVersionFailed {
@@ -30,7 +42,7 @@ pub fn parse_data(data: &[u8]) -> Result<(u8, Tick), CoreError> {
                 cell_name: String::from("TickCell"),
             });
         }
-    }
+    };
 
     Ok((version, tick))
 }
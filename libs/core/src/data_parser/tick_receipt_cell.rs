@@ -0,0 +1,33 @@
+use alloc::string::{String, ToString};
+
+use types::packed::TickReceipt;
+use types::prelude::Entity;
+
+use crate::error::CoreError;
+
+/// The data of TickReceiptCell is a version byte followed by the molecule encoded TickReceipt.
+pub fn parse_data(data: &[u8]) -> Result<(u8, TickReceipt), CoreError> {
+    cc_assert!(
+        !data.is_empty(),
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("TickReceiptCell"),
+            msg: "The data is too short.".to_string(),
+        }
+    );
+
+    let version = data[0];
+    let receipt = match version {
+        0 => TickReceipt::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+            cell_name: String::from("TickReceiptCell"),
+            msg: "Parse slice to TickReceipt failed.".to_string(),
+        })?,
+        _ => {
+            return Err(CoreError::ParseCellDataVersionFailed {
+                version,
+                cell_name: String::from("TickReceiptCell"),
+            });
+        }
+    };
+
+    Ok((version, receipt))
+}
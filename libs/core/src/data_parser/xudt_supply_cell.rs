@@ -0,0 +1,33 @@
+use alloc::string::{String, ToString};
+
+use types::packed::XudtSupplies;
+use types::prelude::Entity;
+
+use crate::error::CoreError;
+
+/// The data of XudtSupplyCell is a version byte followed by the molecule encoded XudtSupplies.
+pub fn parse_data(data: &[u8]) -> Result<(u8, XudtSupplies), CoreError> {
+    cc_assert!(
+        !data.is_empty(),
+        CoreError::ParseCellDataFailed {
+            cell_name: String::from("XudtSupplyCell"),
+            msg: "The data is too short.".to_string(),
+        }
+    );
+
+    let version = data[0];
+    let supplies = match version {
+        0 => XudtSupplies::from_compatible_slice(&data[1..]).map_err(|_| CoreError::ParseCellDataFailed {
+            cell_name: String::from("XudtSupplyCell"),
+            msg: "Parse slice to XudtSupplies failed.".to_string(),
+        })?,
+        _ => {
+            return Err(CoreError::ParseCellDataVersionFailed {
+                version,
+                cell_name: String::from("XudtSupplyCell"),
+            });
+        }
+    };
+
+    Ok((version, supplies))
+}
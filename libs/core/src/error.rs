@@ -40,8 +40,8 @@ pub enum CoreError {
     OwnerLockIsRequired { index: usize },
     #[error("{source:?}[{index}] The cell must have always success lock.")]
     AlwaysSuccessLockIsRequired { index: usize, source: Source },
-    #[error("inputs[{index}] The cell must have custodian lock.")]
-    CustodianLockIsRequired { index: usize },
+    #[error("The custodian lock is required in inputs.")]
+    CustodianLockIsRequired,
     #[error("The {cell_name}.lock must be owner lock.")]
     CellLockMustBeOwnerLock { cell_name: String },
     #[error("The {cell_name}.capacity must be consistent.")]
@@ -63,12 +63,30 @@ pub enum CoreError {
         expected: String,
         current: String,
     },
-    #[error("inputs[{index}] The cell must have merchant lock.")]
-    MerchantLockIsRequired { index: usize },
+    #[error("The merchant lock {lock} is required in inputs.")]
+    MerchantLockIsRequired { lock: String },
     #[error("The system status is off.")]
     SystemStatusOff,
     #[error("Parse length value field {field_name} failed.")]
     ParseLvFailed { field_name: String },
+    #[error("The since 0x{since:016x} is invalid: {msg}")]
+    InvalidSince { since: u64, msg: String },
+    #[error("The SMT proof is invalid: {msg}")]
+    InvalidSmtProof { msg: String },
+    #[error("The action {action} is paused.")]
+    ActionPaused { action: String },
+    #[error("The token 0x{token_id} is frozen.")]
+    TokenFrozen { token_id: String },
+    #[error("The owner lock is required in inputs.")]
+    OwnerLockIsRequiredInInputs,
+    #[error(
+        "cell_deps[{index}] The parent_id of the merchant shard is invalid.(current: {current}, expected: {expected})"
+    )]
+    MerchantParentIdMismatch {
+        index: usize,
+        current: String,
+        expected: String,
+    },
 }
 
 impl From<SysError> for CoreError {
@@ -110,7 +128,7 @@ impl AsI8 for CoreError {
             CoreError::DeployLockIsRequired { index: _ } => 14,
             CoreError::OwnerLockIsRequired { index: _ } => 15,
             CoreError::AlwaysSuccessLockIsRequired { index: _, source: _ } => 16,
-            CoreError::CustodianLockIsRequired { index: _ } => 17,
+            CoreError::CustodianLockIsRequired => 17,
             CoreError::CellLockMustBeOwnerLock { cell_name: _ } => 18,
             CoreError::CellCapacityMustBeConsistent { cell_name: _ } => 19,
             CoreError::CellLockMustBeConsistent { cell_name: _ } => 20,
@@ -128,9 +146,19 @@ impl AsI8 for CoreError {
                 expected: _,
                 current: _,
             } => 25,
-            CoreError::MerchantLockIsRequired { index: _ } => 26,
+            CoreError::MerchantLockIsRequired { lock: _ } => 26,
             CoreError::SystemStatusOff => 27,
             CoreError::ParseLvFailed { field_name: _ } => 28,
+            CoreError::InvalidSince { since: _, msg: _ } => 29,
+            CoreError::InvalidSmtProof { msg: _ } => 30,
+            CoreError::ActionPaused { action: _ } => 31,
+            CoreError::TokenFrozen { token_id: _ } => 32,
+            CoreError::OwnerLockIsRequiredInInputs => 33,
+            CoreError::MerchantParentIdMismatch {
+                index: _,
+                current: _,
+                expected: _,
+            } => 34,
         }
     }
 }
@@ -11,5 +11,6 @@ pub mod config;
 pub mod constants;
 pub mod data_parser;
 pub mod error;
+pub mod since_util;
 pub mod util;
 pub mod verifiers;
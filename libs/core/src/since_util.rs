@@ -0,0 +1,166 @@
+use alloc::format;
+use alloc::string::ToString;
+
+use crate::error::CoreError;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[repr(u8)]
+pub enum SinceFlag {
+    Relative,
+    Absolute,
+    Epoch,
+    Timestamp,
+    Height,
+}
+
+macro_rules! is_set {
+    ($n:expr, $b:expr) => {
+        $n & (1 << $b) != 0
+    };
+}
+
+macro_rules! is_not_set {
+    ($n:expr, $b:expr) => {
+        $n & (1 << $b) == 0
+    };
+}
+
+const EPOCH_NUMBER_MASK: u64 = 0xFF_FFFF;
+const EPOCH_FRACTION_MASK: u64 = 0xFFFF;
+
+pub fn get_relative_flag(since: u64) -> SinceFlag {
+    if is_set!(since, 63) {
+        SinceFlag::Relative
+    } else {
+        SinceFlag::Absolute
+    }
+}
+
+pub fn get_metric_flag(since: u64) -> Result<SinceFlag, CoreError> {
+    if is_not_set!(since, 62) && is_not_set!(since, 61) {
+        Ok(SinceFlag::Height)
+    } else if is_set!(since, 62) && is_not_set!(since, 61) {
+        Ok(SinceFlag::Timestamp)
+    } else if is_not_set!(since, 62) && is_set!(since, 61) {
+        Ok(SinceFlag::Epoch)
+    } else {
+        Err(CoreError::InvalidSince {
+            since,
+            msg: "The metric flag is undefined.".to_string(),
+        })
+    }
+}
+
+pub fn get_value(since: u64) -> u64 {
+    since & 0b00000000_11111111_11111111_11111111_11111111_11111111_11111111_11111111
+}
+
+/// Build an absolute since of timestamp metric, the timestamp is in seconds.
+pub fn build_absolute_timestamp_since(timestamp: u64) -> u64 {
+    (1 << 62) | get_value(timestamp)
+}
+
+/// Build an absolute since of epoch metric which points to the very beginning of the epoch.
+pub fn build_absolute_epoch_since(epoch_number: u64) -> u64 {
+    (1 << 61) | (epoch_number & EPOCH_NUMBER_MASK)
+}
+
+/// Build a relative since of epoch metric which means the number of whole epochs since the input cell is committed.
+pub fn build_relative_epoch_since(epoch_number: u64) -> u64 {
+    (1 << 63) | build_absolute_epoch_since(epoch_number)
+}
+
+/// Split an epoch value into (number, index, length), an epoch with length 0 is treated as the very beginning of it.
+fn parse_epoch(since: u64) -> Result<(u64, u64, u64), CoreError> {
+    let value = get_value(since);
+    let number = value & EPOCH_NUMBER_MASK;
+    let index = (value >> 24) & EPOCH_FRACTION_MASK;
+    let length = (value >> 40) & EPOCH_FRACTION_MASK;
+
+    if length == 0 {
+        cc_assert!(
+            index == 0,
+            CoreError::InvalidSince {
+                since,
+                msg: "The epoch index should be 0 when the epoch length is 0.".to_string(),
+            }
+        );
+        return Ok((number, 0, 1));
+    }
+
+    cc_assert!(
+        index < length,
+        CoreError::InvalidSince {
+            since,
+            msg: "The epoch index should be less than the epoch length.".to_string(),
+        }
+    );
+
+    Ok((number, index, length))
+}
+
+/// Verify that the since value is an absolute one with a valid metric, so it can be used as a deadline.
+pub fn verify_absolute_since(since: u64) -> Result<SinceFlag, CoreError> {
+    verify_since(since, SinceFlag::Absolute)
+}
+
+/// Verify that the since value is a relative one with a valid metric, so it can be used as a delay.
+pub fn verify_relative_since(since: u64) -> Result<SinceFlag, CoreError> {
+    verify_since(since, SinceFlag::Relative)
+}
+
+fn verify_since(since: u64, relative_flag: SinceFlag) -> Result<SinceFlag, CoreError> {
+    cc_assert!(
+        get_relative_flag(since) == relative_flag,
+        CoreError::InvalidSince {
+            since,
+            msg: format!("The since should be {:?}.", relative_flag).to_lowercase(),
+        }
+    );
+
+    let metric = get_metric_flag(since)?;
+    if metric == SinceFlag::Epoch {
+        parse_epoch(since)?;
+    }
+
+    Ok(metric)
+}
+
+/// Check if the since of an input has reached the target, both of them must be absolute and use the same metric.
+pub fn is_since_reached(since: u64, target: u64) -> Result<bool, CoreError> {
+    let target_metric = verify_absolute_since(target)?;
+    let metric = verify_absolute_since(since)?;
+
+    compare_since(since, metric, target, target_metric)
+}
+
+/// Check if the relative since of an input has reached the target, both of them must be relative and use the same
+/// metric.
+pub fn is_relative_since_reached(since: u64, target: u64) -> Result<bool, CoreError> {
+    let target_metric = verify_relative_since(target)?;
+    let metric = verify_relative_since(since)?;
+
+    compare_since(since, metric, target, target_metric)
+}
+
+fn compare_since(since: u64, metric: SinceFlag, target: u64, target_metric: SinceFlag) -> Result<bool, CoreError> {
+    cc_assert!(
+        metric == target_metric,
+        CoreError::InvalidSince {
+            since,
+            msg: format!("The metric should be {:?}, but {:?} found.", target_metric, metric),
+        }
+    );
+
+    let reached = match metric {
+        SinceFlag::Epoch => {
+            let (number, index, length) = parse_epoch(since)?;
+            let (target_number, target_index, target_length) = parse_epoch(target)?;
+
+            number > target_number || (number == target_number && index * target_length >= target_index * length)
+        }
+        _ => get_value(since) >= get_value(target),
+    };
+
+    Ok(reached)
+}
@@ -1,21 +1,23 @@
 use alloc::borrow::ToOwned;
-use alloc::format;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 use ckb_std::ckb_constants::Source;
 use ckb_std::ckb_types::core::ScriptHashType;
 use ckb_std::high_level;
-use ckb_std::high_level::load_cell_lock;
 use types::constants::{deploy_lock, owner_lock, GovernanceMemberRole};
-use types::packed::{Byte32, GovernanceMembers, Reader, Script};
+use types::packed::{Byte32, GovernanceMembers, Reader, Script, SmtProof};
 use types::prelude::{Builder, Entity};
-use types::util::hex_string;
+use types::smt::SMT_VALUE_EXISTING;
+use types::util::{blake2b_256, hex_string};
 
 use crate::config::{always_success_lock, governance_member_cell_type_id, omni_lock_type_id};
 use crate::constants::ScriptType;
-use crate::data_parser::governance_member_cell;
+use crate::data_parser::governance_member_cell::{self, VersionedGovernanceMembers};
 use crate::error::CoreError;
 use crate::util;
+use crate::verifiers::smt;
 
 pub fn verify_input_has_deploy_lock(index: usize) -> Result<(), CoreError> {
     let deploy_lock = deploy_lock();
@@ -37,6 +39,17 @@ pub fn verify_input_has_owner_lock(index: usize) -> Result<(), CoreError> {
     Ok(())
 }
 
+pub fn verify_inputs_have_owner_lock() -> Result<usize, CoreError> {
+    debug!("Verify if the inputs have owner lock.");
+
+    let owner_lock = owner_lock();
+    let cells = util::find_cells_by_script(ScriptType::Lock, owner_lock.as_reader(), Source::Input)?;
+
+    cc_assert!(!cells.is_empty(), CoreError::OwnerLockIsRequiredInInputs);
+
+    Ok(cells[0])
+}
+
 pub fn verify_governance_cell_role(
     expected_role: GovernanceMemberRole,
     index: usize,
@@ -74,72 +87,200 @@ pub fn verify_governance_cell_role(
     Ok(())
 }
 
-pub fn verify_and_find_governance_cell(role: GovernanceMemberRole, source: Source) -> Result<usize, CoreError> {
+pub fn verify_and_find_governance_cells(role: GovernanceMemberRole, source: Source) -> Result<Vec<usize>, CoreError> {
     let type_id = Byte32::try_from(governance_member_cell_type_id()?).unwrap();
     debug!(
-        "Find GovernanceMemberCell by type_id: {}",
+        "Find GovernanceMemberCells by type_id: {}",
         hex_string(type_id.as_reader().as_slice())
     );
-    let cell_index =
-        util::find_only_cell_by_type_id("GovernanceMemberCell", ScriptType::Type, type_id.as_reader(), source)?;
+    let cells = util::find_cells_by_type_id(ScriptType::Type, type_id.as_reader(), source)?;
 
     let expected_lock = match role {
-        GovernanceMemberRole::Custodian => owner_lock().to_owned(),
+        GovernanceMemberRole::Custodian | GovernanceMemberRole::CustodianProposal => owner_lock().to_owned(),
         GovernanceMemberRole::Merchant => always_success_lock()?,
     };
-    let lock = high_level::load_cell_lock(cell_index, source)?;
+    let mut cell_indexes = Vec::new();
+    for cell_index in cells {
+        let (cell_role, _cell_id) = util::load_governance_member_type_info(cell_index, source)?;
+        if cell_role != role {
+            continue;
+        }
+
+        let lock = high_level::load_cell_lock(cell_index, source)?;
+        cc_assert!(
+            util::is_entity_eq(&expected_lock, &lock),
+            CoreError::GovernanceCellLockMismatch {
+                index: cell_index,
+                source
+            }
+        );
+
+        cell_indexes.push(cell_index);
+    }
+
+    Ok(cell_indexes)
+}
+
+pub fn verify_and_find_governance_cell(role: GovernanceMemberRole, source: Source) -> Result<usize, CoreError> {
+    let cells = verify_and_find_governance_cells(role, source)?;
+
     cc_assert!(
-        util::is_entity_eq(&expected_lock, &lock),
-        CoreError::GovernanceCellLockMismatch {
-            index: cell_index,
-            source
+        cells.len() == 1,
+        CoreError::InvalidTransactionStructure {
+            msg: format!(
+                "Only one GovernanceMemberCell of {} expected existing in this transaction, but found {:?} in {:?}.",
+                role,
+                cells.len(),
+                source
+            )
         }
     );
 
-    verify_governance_cell_role(role, cell_index, source)?;
+    Ok(cells[0])
+}
+
+pub fn load_custodian_lock() -> Result<Script, CoreError> {
+    let custodian_cell_index = verify_and_find_governance_cell(GovernanceMemberRole::Custodian, Source::CellDep)?;
 
-    Ok(cell_index)
+    load_custodian_lock_of(custodian_cell_index, Source::CellDep)
 }
 
-pub fn verify_input_has_custodian_lock(index: usize) -> Result<Script, CoreError> {
-    debug!("inputs[{}] Verify if the cell has custodian lock.", index);
+pub fn load_custodian_lock_of(index: usize, source: Source) -> Result<Script, CoreError> {
+    let data = high_level::load_cell_data(index, source).map_err(CoreError::from)?;
+    let members = match governance_member_cell::parse_data(&data)? {
+        (_, VersionedGovernanceMembers::V0(members)) => members,
+        (version, _) => {
+            return Err(CoreError::ParseCellDataVersionFailed {
+                version,
+                cell_name: String::from("GovernanceMemberCell(custodian)"),
+            })
+        }
+    };
 
-    let custodian_cell_index = verify_and_find_governance_cell(GovernanceMemberRole::Custodian, Source::CellDep)?;
+    build_custodian_lock(&members)
+}
 
-    let data = high_level::load_cell_data(custodian_cell_index, Source::CellDep).map_err(CoreError::from)?;
-    let (_version, members) = governance_member_cell::parse_data(&data)?;
+pub fn verify_input_has_custodian_lock() -> Result<(usize, Script), CoreError> {
+    debug!("Verify if the inputs have custodian lock.");
 
-    let custodian_lock = build_custodian_lock(&members)?;
+    let custodian_lock = load_custodian_lock()?;
     let cells = util::find_cells_by_script(ScriptType::Lock, custodian_lock.as_reader(), Source::Input)?;
 
     debug!("Expected custodian lock: {}", custodian_lock);
 
-    cc_assert!(!cells.is_empty(), CoreError::CustodianLockIsRequired { index });
-    cc_assert!(cells[0] == index, CoreError::CustodianLockIsRequired { index });
+    cc_assert!(!cells.is_empty(), CoreError::CustodianLockIsRequired);
 
-    Ok(custodian_lock)
+    Ok((cells[0], custodian_lock))
 }
 
-pub fn verify_input_has_merchant_lock(index: usize) -> Result<(), CoreError> {
-    debug!("inputs[{}] Verify if the cell has merchant lock.", index);
+// A shard of data version 1 only stores the SMT root, the merchant proves its membership in the witness of its
+// first input.
+pub fn verify_input_has_merchant_lock(merchant_lock: &Script) -> Result<usize, CoreError> {
+    debug!("Verify if the inputs have merchant lock: {}", merchant_lock);
+
+    let merchant_cells = verify_and_find_governance_cells(GovernanceMemberRole::Merchant, Source::CellDep)?;
+    cc_assert!(
+        !merchant_cells.is_empty(),
+        CoreError::InvalidTransactionStructure {
+            msg: format!(
+                "At least one GovernanceMemberCell of {} expected existing in {:?}.",
+                GovernanceMemberRole::Merchant,
+                Source::CellDep
+            )
+        }
+    );
 
-    let merchant_cell_index = verify_and_find_governance_cell(GovernanceMemberRole::Merchant, Source::CellDep)?;
+    let cells = util::find_cells_by_script(ScriptType::Lock, merchant_lock.as_reader(), Source::Input)?;
+    cc_assert!(
+        !cells.is_empty(),
+        CoreError::MerchantLockIsRequired {
+            lock: merchant_lock.to_string()
+        }
+    );
 
-    let data = high_level::load_cell_data(merchant_cell_index, Source::CellDep).map_err(CoreError::from)?;
-    let (_version, members) = governance_member_cell::parse_data(&data)?;
+    let custodian_cell_index = verify_and_find_governance_cell(GovernanceMemberRole::Custodian, Source::CellDep)?;
+    let (_, custodian_cell_id) = util::load_governance_member_type_info(custodian_cell_index, Source::CellDep)?;
 
-    let input_lock = load_cell_lock(index, Source::Input)?;
-    let input_lock_slice = input_lock.as_slice();
+    let merchant_lock_slice = merchant_lock.as_slice();
     let mut is_merchant = false;
-    for member in members.members() {
-        debug!("member: {}", hex::encode(member.as_slice()));
-        if member.raw_data().as_ref() == input_lock_slice {
-            is_merchant = true;
+    let mut member_roots = Vec::new();
+    for merchant_cell_index in merchant_cells {
+        let data = high_level::load_cell_data(merchant_cell_index, Source::CellDep).map_err(CoreError::from)?;
+        let (_, members) = governance_member_cell::parse_data(&data)?;
+        let parent_id = match &members {
+            VersionedGovernanceMembers::V0(members) => members.parent_id().raw_data().to_vec(),
+            VersionedGovernanceMembers::V1(members) => members.parent_id().raw_data().to_vec(),
+        };
+        cc_assert!(
+            parent_id == custodian_cell_id,
+            CoreError::MerchantParentIdMismatch {
+                index: merchant_cell_index,
+                current: hex::encode(&parent_id),
+                expected: hex::encode(&custodian_cell_id),
+            }
+        );
+
+        match members {
+            VersionedGovernanceMembers::V0(members) => {
+                for member in members.members() {
+                    debug!("member: {}", hex::encode(member.as_slice()));
+                    if member.raw_data().as_ref() == merchant_lock_slice {
+                        is_merchant = true;
+                        break;
+                    }
+                }
+            }
+            VersionedGovernanceMembers::V1(members) => {
+                let mut root = [0u8; 32];
+                root.copy_from_slice(members.members_root().as_slice());
+                member_roots.push(root);
+            }
+        }
+
+        if is_merchant {
             break;
         }
     }
-    cc_assert!(is_merchant, CoreError::MerchantLockIsRequired { index });
-    Ok(())
+
+    if !is_merchant && !member_roots.is_empty() {
+        is_merchant = verify_merchant_membership_proof(merchant_lock, cells[0], &member_roots)?;
+    }
+    cc_assert!(
+        is_merchant,
+        CoreError::MerchantLockIsRequired {
+            lock: merchant_lock.to_string()
+        }
+    );
+
+    Ok(cells[0])
+}
+
+fn verify_merchant_membership_proof(
+    merchant_lock: &Script,
+    index: usize,
+    roots: &[[u8; 32]],
+) -> Result<bool, CoreError> {
+    debug!("inputs[{}] Verify the merchant membership proof in witness.", index);
+
+    let witness_args = match high_level::load_witness_args(index, Source::Input) {
+        Ok(witness_args) => witness_args,
+        Err(_) => return Ok(false),
+    };
+    let proof = match witness_args.input_type().to_opt() {
+        Some(bytes) => SmtProof::from_compatible_slice(&bytes.raw_data()).map_err(|_| CoreError::InvalidSmtProof {
+            msg: format!("witnesses[{}] Parse input_type to SmtProof failed.", index),
+        })?,
+        None => return Ok(false),
+    };
+
+    let key = blake2b_256(merchant_lock.as_slice());
+    for root in roots {
+        if smt::verify_smt_proof(root, vec![(key, SMT_VALUE_EXISTING)], &proof.raw_data()).is_ok() {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
 }
 
 pub fn verify_cell_has_always_success_lock(index: usize, source: Source) -> Result<(), CoreError> {
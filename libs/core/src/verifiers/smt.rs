@@ -0,0 +1,48 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use types::smt::{Blake2bHasher, CompiledMerkleProof, H256};
+
+use crate::error::CoreError;
+
+/// Verify that the leaves exist in the SMT with the given root, a leaf with zero value means the key does not exist.
+pub fn verify_smt_proof(root: &[u8; 32], leaves: Vec<([u8; 32], [u8; 32])>, proof: &[u8]) -> Result<(), CoreError> {
+    let root = H256::from(*root);
+    let leaves = leaves
+        .into_iter()
+        .map(|(key, value)| (H256::from(key), H256::from(value)))
+        .collect();
+
+    let proof = CompiledMerkleProof(proof.to_vec());
+    let verified = proof
+        .verify::<Blake2bHasher>(&root, leaves)
+        .map_err(|err| CoreError::InvalidSmtProof { msg: err.to_string() })?;
+
+    cc_assert!(
+        verified,
+        CoreError::InvalidSmtProof {
+            msg: "The calculated root does not match.".to_string()
+        }
+    );
+
+    Ok(())
+}
+
+/// Verify that the SMT is updated from old_root to new_root, each leaf is a tuple of (key, old_value, new_value), and
+/// the same proof is used to verify both roots.
+pub fn verify_smt_update(
+    old_root: &[u8; 32],
+    new_root: &[u8; 32],
+    leaves: Vec<([u8; 32], [u8; 32], [u8; 32])>,
+    proof: &[u8],
+) -> Result<(), CoreError> {
+    debug!("Verify the SMT is updated from old root to new root ...");
+
+    let old_leaves = leaves.iter().map(|(key, old, _)| (*key, *old)).collect();
+    verify_smt_proof(old_root, old_leaves, proof)?;
+
+    let new_leaves = leaves.iter().map(|(key, _, new)| (*key, *new)).collect();
+    verify_smt_proof(new_root, new_leaves, proof)?;
+
+    Ok(())
+}
@@ -55,6 +55,10 @@ extern "C" {
 #define                                 MolReader_Uint128_get_nth13(s)                  mol_slice_by_offset(s, 13, 1)
 #define                                 MolReader_Uint128_get_nth14(s)                  mol_slice_by_offset(s, 14, 1)
 #define                                 MolReader_Uint128_get_nth15(s)                  mol_slice_by_offset(s, 15, 1)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_Uint64Opt_verify                      (const mol_seg_t*, bool);
+#define                                 MolReader_Uint64Opt_is_none(s)                  mol_option_is_none(s)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_Uint128Opt_verify                     (const mol_seg_t*, bool);
+#define                                 MolReader_Uint128Opt_is_none(s)                 mol_option_is_none(s)
 #define                                 MolReader_Bytes_verify(s, c)                    mol_fixvec_verify(s, 1)
 #define                                 MolReader_Bytes_length(s)                       mol_fixvec_length(s)
 #define                                 MolReader_Bytes_get(s, i)                       mol_fixvec_slice_by_index(s, 1, i)
@@ -151,6 +155,14 @@ MOLECULE_API_DECORATOR  mol_errno       MolReader_ScriptOpt_verify
 #define                                 MolBuilder_Uint128_set_nth15(b, p)              mol_builder_set_byte_by_offset(b, 15, p)
 #define                                 MolBuilder_Uint128_build(b)                     mol_builder_finalize_simple(b)
 #define                                 MolBuilder_Uint128_clear(b)                     mol_builder_discard(b)
+#define                                 MolBuilder_Uint64Opt_init(b)                    mol_builder_initialize_fixed_size(b, 0)
+#define                                 MolBuilder_Uint64Opt_set(b, p, l)               mol_option_builder_set(b, p, l)
+#define                                 MolBuilder_Uint64Opt_build(b)                   mol_builder_finalize_simple(b)
+#define                                 MolBuilder_Uint64Opt_clear(b)                   mol_builder_discard(b)
+#define                                 MolBuilder_Uint128Opt_init(b)                   mol_builder_initialize_fixed_size(b, 0)
+#define                                 MolBuilder_Uint128Opt_set(b, p, l)              mol_option_builder_set(b, p, l)
+#define                                 MolBuilder_Uint128Opt_build(b)                  mol_builder_finalize_simple(b)
+#define                                 MolBuilder_Uint128Opt_clear(b)                  mol_builder_discard(b)
 #define                                 MolBuilder_Bytes_init(b)                        mol_fixvec_builder_initialize(b, 16)
 #define                                 MolBuilder_Bytes_push(b, p)                     mol_fixvec_builder_push_byte(b, p)
 #define                                 MolBuilder_Bytes_build(b)                       mol_fixvec_builder_finalize(b)
@@ -224,6 +236,8 @@ MOLECULE_API_DECORATOR const uint8_t MolDefault_Uint128[16]      =  {
     ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
     ____, ____, ____, ____,
 };
+MOLECULE_API_DECORATOR const uint8_t MolDefault_Uint64Opt[0]     =  {};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_Uint128Opt[0]    =  {};
 MOLECULE_API_DECORATOR const uint8_t MolDefault_Bytes[4]         =  {____, ____, ____, ____};
 MOLECULE_API_DECORATOR const uint8_t MolDefault_BytesOpt[0]      =  {};
 MOLECULE_API_DECORATOR const uint8_t MolDefault_BytesVec[4]      =  {0x04, ____, ____, ____};
@@ -247,6 +261,20 @@ MOLECULE_API_DECORATOR const uint8_t MolDefault_ScriptOpt[0]     =  {};
  * Reader Functions
  */
 
+MOLECULE_API_DECORATOR mol_errno MolReader_Uint64Opt_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size != 0) {
+        return MolReader_Uint64_verify(input, compatible);
+    } else {
+        return MOL_OK;
+    }
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_Uint128Opt_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size != 0) {
+        return MolReader_Uint128_verify(input, compatible);
+    } else {
+        return MOL_OK;
+    }
+}
 MOLECULE_API_DECORATOR mol_errno MolReader_BytesOpt_verify (const mol_seg_t *input, bool compatible) {
     if (input->size != 0) {
         return MolReader_Bytes_verify(input, compatible);
@@ -31,6 +31,17 @@ MOLECULE_API_DECORATOR  mol_errno       MolReader_GovernanceMembers_verify
 #define                                 MolReader_GovernanceMembers_get_lock_args(s)    mol_table_slice_by_index(s, 1)
 #define                                 MolReader_GovernanceMembers_get_multisig_args(s) mol_table_slice_by_index(s, 2)
 #define                                 MolReader_GovernanceMembers_get_members(s)      mol_table_slice_by_index(s, 3)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_GovernanceMembersV1_verify            (const mol_seg_t*, bool);
+#define                                 MolReader_GovernanceMembersV1_actual_field_count(s) mol_table_actual_field_count(s)
+#define                                 MolReader_GovernanceMembersV1_has_extra_fields(s) mol_table_has_extra_fields(s, 2)
+#define                                 MolReader_GovernanceMembersV1_get_parent_id(s)  mol_table_slice_by_index(s, 0)
+#define                                 MolReader_GovernanceMembersV1_get_members_root(s) mol_table_slice_by_index(s, 1)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_CustodianProposal_verify              (const mol_seg_t*, bool);
+#define                                 MolReader_CustodianProposal_actual_field_count(s) mol_table_actual_field_count(s)
+#define                                 MolReader_CustodianProposal_has_extra_fields(s) mol_table_has_extra_fields(s, 3)
+#define                                 MolReader_CustodianProposal_get_custodian_id(s) mol_table_slice_by_index(s, 0)
+#define                                 MolReader_CustodianProposal_get_members(s)      mol_table_slice_by_index(s, 1)
+#define                                 MolReader_CustodianProposal_get_activation_epoch(s) mol_table_slice_by_index(s, 2)
 MOLECULE_API_DECORATOR  mol_errno       MolReader_Tick_verify                           (const mol_seg_t*, bool);
 #define                                 MolReader_Tick_actual_field_count(s)            mol_table_actual_field_count(s)
 #define                                 MolReader_Tick_has_extra_fields(s)              mol_table_has_extra_fields(s, 7)
@@ -41,6 +52,61 @@ MOLECULE_API_DECORATOR  mol_errno       MolReader_Tick_verify
 #define                                 MolReader_Tick_get_coin_type(s)                 mol_table_slice_by_index(s, 4)
 #define                                 MolReader_Tick_get_tx_hash(s)                   mol_table_slice_by_index(s, 5)
 #define                                 MolReader_Tick_get_receipt_addr(s)              mol_table_slice_by_index(s, 6)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_TickV1_verify                         (const mol_seg_t*, bool);
+#define                                 MolReader_TickV1_actual_field_count(s)          mol_table_actual_field_count(s)
+#define                                 MolReader_TickV1_has_extra_fields(s)            mol_table_has_extra_fields(s, 11)
+#define                                 MolReader_TickV1_get_tick_type(s)               mol_table_slice_by_index(s, 0)
+#define                                 MolReader_TickV1_get_token_id(s)                mol_table_slice_by_index(s, 1)
+#define                                 MolReader_TickV1_get_value(s)                   mol_table_slice_by_index(s, 2)
+#define                                 MolReader_TickV1_get_merchant(s)                mol_table_slice_by_index(s, 3)
+#define                                 MolReader_TickV1_get_coin_type(s)               mol_table_slice_by_index(s, 4)
+#define                                 MolReader_TickV1_get_tx_hash(s)                 mol_table_slice_by_index(s, 5)
+#define                                 MolReader_TickV1_get_receipt_addr(s)            mol_table_slice_by_index(s, 6)
+#define                                 MolReader_TickV1_get_deadline(s)                mol_table_slice_by_index(s, 7)
+#define                                 MolReader_TickV1_get_escrowed(s)                mol_table_slice_by_index(s, 8)
+#define                                 MolReader_TickV1_get_nonce(s)                   mol_table_slice_by_index(s, 9)
+#define                                 MolReader_TickV1_get_memo(s)                    mol_table_slice_by_index(s, 10)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_BurnPayout_verify                     (const mol_seg_t*, bool);
+#define                                 MolReader_BurnPayout_actual_field_count(s)      mol_table_actual_field_count(s)
+#define                                 MolReader_BurnPayout_has_extra_fields(s)        mol_table_has_extra_fields(s, 2)
+#define                                 MolReader_BurnPayout_get_coin_type(s)           mol_table_slice_by_index(s, 0)
+#define                                 MolReader_BurnPayout_get_tx_hash(s)             mol_table_slice_by_index(s, 1)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_TickReceipt_verify                    (const mol_seg_t*, bool);
+#define                                 MolReader_TickReceipt_actual_field_count(s)     mol_table_actual_field_count(s)
+#define                                 MolReader_TickReceipt_has_extra_fields(s)       mol_table_has_extra_fields(s, 4)
+#define                                 MolReader_TickReceipt_get_tick(s)               mol_table_slice_by_index(s, 0)
+#define                                 MolReader_TickReceipt_get_outcome(s)            mol_table_slice_by_index(s, 1)
+#define                                 MolReader_TickReceipt_get_custodian_lock_hash(s) mol_table_slice_by_index(s, 2)
+#define                                 MolReader_TickReceipt_get_settlement_tx_hash(s) mol_table_slice_by_index(s, 3)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_MintQuota_verify                      (const mol_seg_t*, bool);
+#define                                 MolReader_MintQuota_actual_field_count(s)       mol_table_actual_field_count(s)
+#define                                 MolReader_MintQuota_has_extra_fields(s)         mol_table_has_extra_fields(s, 7)
+#define                                 MolReader_MintQuota_get_token_id(s)             mol_table_slice_by_index(s, 0)
+#define                                 MolReader_MintQuota_get_merchant(s)             mol_table_slice_by_index(s, 1)
+#define                                 MolReader_MintQuota_get_max_request_value(s)    mol_table_slice_by_index(s, 2)
+#define                                 MolReader_MintQuota_get_cap(s)                  mol_table_slice_by_index(s, 3)
+#define                                 MolReader_MintQuota_get_period(s)               mol_table_slice_by_index(s, 4)
+#define                                 MolReader_MintQuota_get_period_start(s)         mol_table_slice_by_index(s, 5)
+#define                                 MolReader_MintQuota_get_minted(s)               mol_table_slice_by_index(s, 6)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_MintQuotas_verify                     (const mol_seg_t*, bool);
+#define                                 MolReader_MintQuotas_length(s)                  mol_dynvec_length(s)
+#define                                 MolReader_MintQuotas_get(s, i)                  mol_dynvec_slice_by_index(s, i)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_XudtSupply_verify                     (const mol_seg_t*, bool);
+#define                                 MolReader_XudtSupply_actual_field_count(s)      mol_table_actual_field_count(s)
+#define                                 MolReader_XudtSupply_has_extra_fields(s)        mol_table_has_extra_fields(s, 3)
+#define                                 MolReader_XudtSupply_get_token_id(s)            mol_table_slice_by_index(s, 0)
+#define                                 MolReader_XudtSupply_get_supply(s)              mol_table_slice_by_index(s, 1)
+#define                                 MolReader_XudtSupply_get_max_supply(s)          mol_table_slice_by_index(s, 2)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_XudtSupplies_verify                   (const mol_seg_t*, bool);
+#define                                 MolReader_XudtSupplies_length(s)                mol_dynvec_length(s)
+#define                                 MolReader_XudtSupplies_get(s, i)                mol_dynvec_slice_by_index(s, i)
+MOLECULE_API_DECORATOR  mol_errno       MolReader_ReserveAttestation_verify             (const mol_seg_t*, bool);
+#define                                 MolReader_ReserveAttestation_actual_field_count(s) mol_table_actual_field_count(s)
+#define                                 MolReader_ReserveAttestation_has_extra_fields(s) mol_table_has_extra_fields(s, 4)
+#define                                 MolReader_ReserveAttestation_get_coin_type(s)   mol_table_slice_by_index(s, 0)
+#define                                 MolReader_ReserveAttestation_get_reserve(s)     mol_table_slice_by_index(s, 1)
+#define                                 MolReader_ReserveAttestation_get_block_height(s) mol_table_slice_by_index(s, 2)
+#define                                 MolReader_ReserveAttestation_get_timestamp(s)   mol_table_slice_by_index(s, 3)
 #define                                 MolReader_Auth_verify(s, c)                     mol_verify_fixed_size(s, 21)
 #define                                 MolReader_Auth_get_nth0(s)                      mol_slice_by_offset(s, 0, 1)
 #define                                 MolReader_Auth_get_nth1(s)                      mol_slice_by_offset(s, 1, 1)
@@ -83,6 +149,17 @@ MOLECULE_API_DECORATOR  mol_errno       MolReader_OmniLockWitnessLock_verify
 #define                                 MolBuilder_GovernanceMembers_set_members(b, p, l) mol_table_builder_add(b, 3, p, l)
 MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_GovernanceMembers_build              (mol_builder_t);
 #define                                 MolBuilder_GovernanceMembers_clear(b)           mol_builder_discard(b)
+#define                                 MolBuilder_GovernanceMembersV1_init(b)          mol_table_builder_initialize(b, 256, 2)
+#define                                 MolBuilder_GovernanceMembersV1_set_parent_id(b, p, l) mol_table_builder_add(b, 0, p, l)
+#define                                 MolBuilder_GovernanceMembersV1_set_members_root(b, p, l) mol_table_builder_add(b, 1, p, l)
+MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_GovernanceMembersV1_build            (mol_builder_t);
+#define                                 MolBuilder_GovernanceMembersV1_clear(b)         mol_builder_discard(b)
+#define                                 MolBuilder_CustodianProposal_init(b)            mol_table_builder_initialize(b, 256, 3)
+#define                                 MolBuilder_CustodianProposal_set_custodian_id(b, p, l) mol_table_builder_add(b, 0, p, l)
+#define                                 MolBuilder_CustodianProposal_set_members(b, p, l) mol_table_builder_add(b, 1, p, l)
+#define                                 MolBuilder_CustodianProposal_set_activation_epoch(b, p, l) mol_table_builder_add(b, 2, p, l)
+MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_CustodianProposal_build              (mol_builder_t);
+#define                                 MolBuilder_CustodianProposal_clear(b)           mol_builder_discard(b)
 #define                                 MolBuilder_Tick_init(b)                         mol_table_builder_initialize(b, 512, 7)
 #define                                 MolBuilder_Tick_set_tick_type(b, p)             mol_table_builder_add_byte(b, 0, p)
 #define                                 MolBuilder_Tick_set_token_id(b, p, l)           mol_table_builder_add(b, 1, p, l)
@@ -93,6 +170,63 @@ MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_GovernanceMembers_build
 #define                                 MolBuilder_Tick_set_receipt_addr(b, p, l)       mol_table_builder_add(b, 6, p, l)
 MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_Tick_build                           (mol_builder_t);
 #define                                 MolBuilder_Tick_clear(b)                        mol_builder_discard(b)
+#define                                 MolBuilder_TickV1_init(b)                       mol_table_builder_initialize(b, 1024, 11)
+#define                                 MolBuilder_TickV1_set_tick_type(b, p)           mol_table_builder_add_byte(b, 0, p)
+#define                                 MolBuilder_TickV1_set_token_id(b, p, l)         mol_table_builder_add(b, 1, p, l)
+#define                                 MolBuilder_TickV1_set_value(b, p, l)            mol_table_builder_add(b, 2, p, l)
+#define                                 MolBuilder_TickV1_set_merchant(b, p, l)         mol_table_builder_add(b, 3, p, l)
+#define                                 MolBuilder_TickV1_set_coin_type(b, p, l)        mol_table_builder_add(b, 4, p, l)
+#define                                 MolBuilder_TickV1_set_tx_hash(b, p, l)          mol_table_builder_add(b, 5, p, l)
+#define                                 MolBuilder_TickV1_set_receipt_addr(b, p, l)     mol_table_builder_add(b, 6, p, l)
+#define                                 MolBuilder_TickV1_set_deadline(b, p, l)         mol_table_builder_add(b, 7, p, l)
+#define                                 MolBuilder_TickV1_set_escrowed(b, p)            mol_table_builder_add_byte(b, 8, p)
+#define                                 MolBuilder_TickV1_set_nonce(b, p, l)            mol_table_builder_add(b, 9, p, l)
+#define                                 MolBuilder_TickV1_set_memo(b, p, l)             mol_table_builder_add(b, 10, p, l)
+MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_TickV1_build                         (mol_builder_t);
+#define                                 MolBuilder_TickV1_clear(b)                      mol_builder_discard(b)
+#define                                 MolBuilder_BurnPayout_init(b)                   mol_table_builder_initialize(b, 128, 2)
+#define                                 MolBuilder_BurnPayout_set_coin_type(b, p, l)    mol_table_builder_add(b, 0, p, l)
+#define                                 MolBuilder_BurnPayout_set_tx_hash(b, p, l)      mol_table_builder_add(b, 1, p, l)
+MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_BurnPayout_build                     (mol_builder_t);
+#define                                 MolBuilder_BurnPayout_clear(b)                  mol_builder_discard(b)
+#define                                 MolBuilder_TickReceipt_init(b)                  mol_table_builder_initialize(b, 256, 4)
+#define                                 MolBuilder_TickReceipt_set_tick(b, p, l)        mol_table_builder_add(b, 0, p, l)
+#define                                 MolBuilder_TickReceipt_set_outcome(b, p)        mol_table_builder_add_byte(b, 1, p)
+#define                                 MolBuilder_TickReceipt_set_custodian_lock_hash(b, p, l) mol_table_builder_add(b, 2, p, l)
+#define                                 MolBuilder_TickReceipt_set_settlement_tx_hash(b, p, l) mol_table_builder_add(b, 3, p, l)
+MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_TickReceipt_build                    (mol_builder_t);
+#define                                 MolBuilder_TickReceipt_clear(b)                 mol_builder_discard(b)
+#define                                 MolBuilder_MintQuota_init(b)                    mol_table_builder_initialize(b, 1024, 7)
+#define                                 MolBuilder_MintQuota_set_token_id(b, p, l)      mol_table_builder_add(b, 0, p, l)
+#define                                 MolBuilder_MintQuota_set_merchant(b, p, l)      mol_table_builder_add(b, 1, p, l)
+#define                                 MolBuilder_MintQuota_set_max_request_value(b, p, l) mol_table_builder_add(b, 2, p, l)
+#define                                 MolBuilder_MintQuota_set_cap(b, p, l)           mol_table_builder_add(b, 3, p, l)
+#define                                 MolBuilder_MintQuota_set_period(b, p, l)        mol_table_builder_add(b, 4, p, l)
+#define                                 MolBuilder_MintQuota_set_period_start(b, p, l)  mol_table_builder_add(b, 5, p, l)
+#define                                 MolBuilder_MintQuota_set_minted(b, p, l)        mol_table_builder_add(b, 6, p, l)
+MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_MintQuota_build                      (mol_builder_t);
+#define                                 MolBuilder_MintQuota_clear(b)                   mol_builder_discard(b)
+#define                                 MolBuilder_MintQuotas_init(b)                   mol_builder_initialize_with_capacity(b, 4096, 64)
+#define                                 MolBuilder_MintQuotas_push(b, p, l)             mol_dynvec_builder_push(b, p, l)
+#define                                 MolBuilder_MintQuotas_build(b)                  mol_dynvec_builder_finalize(b)
+#define                                 MolBuilder_MintQuotas_clear(b)                  mol_builder_discard(b)
+#define                                 MolBuilder_XudtSupply_init(b)                   mol_table_builder_initialize(b, 256, 3)
+#define                                 MolBuilder_XudtSupply_set_token_id(b, p, l)     mol_table_builder_add(b, 0, p, l)
+#define                                 MolBuilder_XudtSupply_set_supply(b, p, l)       mol_table_builder_add(b, 1, p, l)
+#define                                 MolBuilder_XudtSupply_set_max_supply(b, p, l)   mol_table_builder_add(b, 2, p, l)
+MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_XudtSupply_build                     (mol_builder_t);
+#define                                 MolBuilder_XudtSupply_clear(b)                  mol_builder_discard(b)
+#define                                 MolBuilder_XudtSupplies_init(b)                 mol_builder_initialize_with_capacity(b, 1024, 64)
+#define                                 MolBuilder_XudtSupplies_push(b, p, l)           mol_dynvec_builder_push(b, p, l)
+#define                                 MolBuilder_XudtSupplies_build(b)                mol_dynvec_builder_finalize(b)
+#define                                 MolBuilder_XudtSupplies_clear(b)                mol_builder_discard(b)
+#define                                 MolBuilder_ReserveAttestation_init(b)           mol_table_builder_initialize(b, 256, 4)
+#define                                 MolBuilder_ReserveAttestation_set_coin_type(b, p, l) mol_table_builder_add(b, 0, p, l)
+#define                                 MolBuilder_ReserveAttestation_set_reserve(b, p, l) mol_table_builder_add(b, 1, p, l)
+#define                                 MolBuilder_ReserveAttestation_set_block_height(b, p, l) mol_table_builder_add(b, 2, p, l)
+#define                                 MolBuilder_ReserveAttestation_set_timestamp(b, p, l) mol_table_builder_add(b, 3, p, l)
+MOLECULE_API_DECORATOR  mol_seg_res_t   MolBuilder_ReserveAttestation_build             (mol_builder_t);
+#define                                 MolBuilder_ReserveAttestation_clear(b)          mol_builder_discard(b)
 #define                                 MolBuilder_Auth_init(b)                         mol_builder_initialize_fixed_size(b, 21)
 #define                                 MolBuilder_Auth_set_nth0(b, p)                  mol_builder_set_byte_by_offset(b, 0, p)
 #define                                 MolBuilder_Auth_set_nth1(b, p)                  mol_builder_set_byte_by_offset(b, 1, p)
@@ -139,6 +273,20 @@ MOLECULE_API_DECORATOR const uint8_t MolDefault_GovernanceMembers[36] =  {
     0x1c, ____, ____, ____, 0x20, ____, ____, ____, ____, ____, ____, ____,
     ____, ____, ____, ____, ____, ____, ____, ____, 0x04, ____, ____, ____,
 };
+MOLECULE_API_DECORATOR const uint8_t MolDefault_GovernanceMembersV1[48] =  {
+    0x30, ____, ____, ____, 0x0c, ____, ____, ____, 0x10, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_CustodianProposal[64] =  {
+    0x40, ____, ____, ____, 0x10, ____, ____, ____, 0x14, ____, ____, ____,
+    0x38, ____, ____, ____, ____, ____, ____, ____, 0x24, ____, ____, ____,
+    0x14, ____, ____, ____, 0x18, ____, ____, ____, 0x1c, ____, ____, ____,
+    0x20, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, 0x04, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____,
+};
 MOLECULE_API_DECORATOR const uint8_t MolDefault_Tick[118]        =  {
     0x76, ____, ____, ____, 0x20, ____, ____, ____, 0x21, ____, ____, ____,
     0x25, ____, ____, ____, 0x35, ____, ____, ____, 0x6a, ____, ____, ____,
@@ -151,6 +299,62 @@ MOLECULE_API_DECORATOR const uint8_t MolDefault_Tick[118]        =  {
     ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
     ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
 };
+MOLECULE_API_DECORATOR const uint8_t MolDefault_TickV1[147]      =  {
+    0x93, ____, ____, ____, 0x30, ____, ____, ____, 0x31, ____, ____, ____,
+    0x35, ____, ____, ____, 0x45, ____, ____, ____, 0x7a, ____, ____, ____,
+    0x7e, ____, ____, ____, 0x82, ____, ____, ____, 0x86, ____, ____, ____,
+    0x86, ____, ____, ____, 0x87, ____, ____, ____, 0x8f, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, 0x35, ____, ____,
+    ____, 0x10, ____, ____, ____, 0x30, ____, ____, ____, 0x31, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____,
+};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_BurnPayout[20]   =  {
+    0x14, ____, ____, ____, 0x0c, ____, ____, ____, 0x10, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____,
+};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_TickReceipt[61]  =  {
+    0x3d, ____, ____, ____, 0x14, ____, ____, ____, 0x18, ____, ____, ____,
+    0x19, ____, ____, ____, 0x39, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____,
+};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_MintQuota[153]   =  {
+    0x99, ____, ____, ____, 0x20, ____, ____, ____, 0x24, ____, ____, ____,
+    0x59, ____, ____, ____, 0x69, ____, ____, ____, 0x79, ____, ____, ____,
+    0x81, ____, ____, ____, 0x89, ____, ____, ____, ____, ____, ____, ____,
+    0x35, ____, ____, ____, 0x10, ____, ____, ____, 0x30, ____, ____, ____,
+    0x31, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____,
+};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_MintQuotas[4]    =  {0x04, ____, ____, ____};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_XudtSupply[36]   =  {
+    0x24, ____, ____, ____, 0x10, ____, ____, ____, 0x14, ____, ____, ____,
+    0x24, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_XudtSupplies[4]  =  {0x04, ____, ____, ____};
+MOLECULE_API_DECORATOR const uint8_t MolDefault_ReserveAttestation[56] =  {
+    0x38, ____, ____, ____, 0x14, ____, ____, ____, 0x18, ____, ____, ____,
+    0x28, ____, ____, ____, 0x30, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
+    ____, ____, ____, ____, ____, ____, ____, ____,
+};
 MOLECULE_API_DECORATOR const uint8_t MolDefault_Auth[21]         =  {
     ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____, ____,
     ____, ____, ____, ____, ____, ____, ____, ____, ____,
@@ -234,6 +438,122 @@ MOLECULE_API_DECORATOR mol_errno MolReader_GovernanceMembers_verify (const mol_s
         }
     return MOL_OK;
 }
+MOLECULE_API_DECORATOR mol_errno MolReader_GovernanceMembersV1_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t field_count = offset / 4 - 1;
+    if (field_count < 2) {
+        return MOL_ERR_FIELD_COUNT;
+    } else if (!compatible && field_count > 2) {
+        return MOL_ERR_FIELD_COUNT;
+    }
+    if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t offsets[field_count+1];
+    offsets[0] = offset;
+    for (mol_num_t i=1; i<field_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        offsets[i] = mol_unpack_number(ptr);
+        if (offsets[i-1] > offsets[i]) {
+            return MOL_ERR_OFFSET;
+        }
+    }
+    if (offsets[field_count-1] > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    offsets[field_count] = total_size;
+        mol_seg_t inner;
+        mol_errno errno;
+        inner.ptr = input->ptr + offsets[0];
+        inner.size = offsets[1] - offsets[0];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[1];
+        inner.size = offsets[2] - offsets[1];
+        errno = MolReader_Byte32_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+    return MOL_OK;
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_CustodianProposal_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t field_count = offset / 4 - 1;
+    if (field_count < 3) {
+        return MOL_ERR_FIELD_COUNT;
+    } else if (!compatible && field_count > 3) {
+        return MOL_ERR_FIELD_COUNT;
+    }
+    if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t offsets[field_count+1];
+    offsets[0] = offset;
+    for (mol_num_t i=1; i<field_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        offsets[i] = mol_unpack_number(ptr);
+        if (offsets[i-1] > offsets[i]) {
+            return MOL_ERR_OFFSET;
+        }
+    }
+    if (offsets[field_count-1] > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    offsets[field_count] = total_size;
+        mol_seg_t inner;
+        mol_errno errno;
+        inner.ptr = input->ptr + offsets[0];
+        inner.size = offsets[1] - offsets[0];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[1];
+        inner.size = offsets[2] - offsets[1];
+        errno = MolReader_GovernanceMembers_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[2];
+        inner.size = offsets[3] - offsets[2];
+        errno = MolReader_Uint64_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+    return MOL_OK;
+}
 MOLECULE_API_DECORATOR mol_errno MolReader_Tick_verify (const mol_seg_t *input, bool compatible) {
     if (input->size < MOL_NUM_T_SIZE) {
         return MOL_ERR_HEADER;
@@ -316,14 +636,7 @@ MOLECULE_API_DECORATOR mol_errno MolReader_Tick_verify (const mol_seg_t *input,
         }
     return MOL_OK;
 }
-MOLECULE_API_DECORATOR mol_errno MolReader_IdentityOpt_verify (const mol_seg_t *input, bool compatible) {
-    if (input->size != 0) {
-        return MolReader_Bytes_verify(input, compatible);
-    } else {
-        return MOL_OK;
-    }
-}
-MOLECULE_API_DECORATOR mol_errno MolReader_OmniLockWitnessLock_verify (const mol_seg_t *input, bool compatible) {
+MOLECULE_API_DECORATOR mol_errno MolReader_TickV1_verify (const mol_seg_t *input, bool compatible) {
     if (input->size < MOL_NUM_T_SIZE) {
         return MOL_ERR_HEADER;
     }
@@ -341,9 +654,9 @@ MOLECULE_API_DECORATOR mol_errno MolReader_OmniLockWitnessLock_verify (const mol
         return MOL_ERR_OFFSET;
     }
     mol_num_t field_count = offset / 4 - 1;
-    if (field_count < 3) {
+    if (field_count < 11) {
         return MOL_ERR_FIELD_COUNT;
-    } else if (!compatible && field_count > 3) {
+    } else if (!compatible && field_count > 11) {
         return MOL_ERR_FIELD_COUNT;
     }
     if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
@@ -364,88 +677,1145 @@ MOLECULE_API_DECORATOR mol_errno MolReader_OmniLockWitnessLock_verify (const mol
     offsets[field_count] = total_size;
         mol_seg_t inner;
         mol_errno errno;
-        inner.ptr = input->ptr + offsets[0];
-        inner.size = offsets[1] - offsets[0];
-        errno = MolReader_BytesOpt_verify(&inner, compatible);
-        if (errno != MOL_OK) {
+        if (offsets[1] - offsets[0] != 1) {
             return MOL_ERR_DATA;
         }
         inner.ptr = input->ptr + offsets[1];
         inner.size = offsets[2] - offsets[1];
-        errno = MolReader_IdentityOpt_verify(&inner, compatible);
+        errno = MolReader_Bytes_verify(&inner, compatible);
         if (errno != MOL_OK) {
             return MOL_ERR_DATA;
         }
         inner.ptr = input->ptr + offsets[2];
         inner.size = offsets[3] - offsets[2];
-        errno = MolReader_BytesOpt_verify(&inner, compatible);
+        errno = MolReader_Uint128_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[3];
+        inner.size = offsets[4] - offsets[3];
+        errno = MolReader_Script_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[4];
+        inner.size = offsets[5] - offsets[4];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[5];
+        inner.size = offsets[6] - offsets[5];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[6];
+        inner.size = offsets[7] - offsets[6];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[7];
+        inner.size = offsets[8] - offsets[7];
+        errno = MolReader_Uint64Opt_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        if (offsets[9] - offsets[8] != 1) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[9];
+        inner.size = offsets[10] - offsets[9];
+        errno = MolReader_Uint64_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[10];
+        inner.size = offsets[11] - offsets[10];
+        errno = MolReader_Bytes_verify(&inner, compatible);
         if (errno != MOL_OK) {
             return MOL_ERR_DATA;
         }
     return MOL_OK;
 }
-
-/*
- * Builder Functions
- */
-
-MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_GovernanceMembers_build (mol_builder_t builder) {
-    mol_seg_res_t res;
-    res.errno = MOL_OK;
-    mol_num_t offset = 20;
-    mol_num_t len;
-    res.seg.size = offset;
-    len = builder.number_ptr[1];
-    res.seg.size += len == 0 ? 4 : len;
-    len = builder.number_ptr[3];
-    res.seg.size += len == 0 ? 4 : len;
-    len = builder.number_ptr[5];
-    res.seg.size += len == 0 ? 4 : len;
-    len = builder.number_ptr[7];
-    res.seg.size += len == 0 ? 4 : len;
-    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
-    uint8_t *dst = res.seg.ptr;
-    mol_pack_number(dst, &res.seg.size);
-    dst += MOL_NUM_T_SIZE;
-    mol_pack_number(dst, &offset);
-    dst += MOL_NUM_T_SIZE;
-    len = builder.number_ptr[1];
-    offset += len == 0 ? 4 : len;
-    mol_pack_number(dst, &offset);
-    dst += MOL_NUM_T_SIZE;
-    len = builder.number_ptr[3];
-    offset += len == 0 ? 4 : len;
-    mol_pack_number(dst, &offset);
-    dst += MOL_NUM_T_SIZE;
-    len = builder.number_ptr[5];
-    offset += len == 0 ? 4 : len;
-    mol_pack_number(dst, &offset);
-    dst += MOL_NUM_T_SIZE;
-    len = builder.number_ptr[7];
-    offset += len == 0 ? 4 : len;
-    uint8_t *src = builder.data_ptr;
-    len = builder.number_ptr[1];
-    if (len == 0) {
-        len = 4;
-        memcpy(dst, &MolDefault_Bytes, len);
-    } else {
-        mol_num_t of = builder.number_ptr[0];
-        memcpy(dst, src+of, len);
+MOLECULE_API_DECORATOR mol_errno MolReader_BurnPayout_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
     }
-    dst += len;
-    len = builder.number_ptr[3];
-    if (len == 0) {
-        len = 4;
-        memcpy(dst, &MolDefault_Bytes, len);
-    } else {
-        mol_num_t of = builder.number_ptr[2];
-        memcpy(dst, src+of, len);
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t field_count = offset / 4 - 1;
+    if (field_count < 2) {
+        return MOL_ERR_FIELD_COUNT;
+    } else if (!compatible && field_count > 2) {
+        return MOL_ERR_FIELD_COUNT;
+    }
+    if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t offsets[field_count+1];
+    offsets[0] = offset;
+    for (mol_num_t i=1; i<field_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        offsets[i] = mol_unpack_number(ptr);
+        if (offsets[i-1] > offsets[i]) {
+            return MOL_ERR_OFFSET;
+        }
+    }
+    if (offsets[field_count-1] > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    offsets[field_count] = total_size;
+        mol_seg_t inner;
+        mol_errno errno;
+        inner.ptr = input->ptr + offsets[0];
+        inner.size = offsets[1] - offsets[0];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[1];
+        inner.size = offsets[2] - offsets[1];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+    return MOL_OK;
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_TickReceipt_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t field_count = offset / 4 - 1;
+    if (field_count < 4) {
+        return MOL_ERR_FIELD_COUNT;
+    } else if (!compatible && field_count > 4) {
+        return MOL_ERR_FIELD_COUNT;
+    }
+    if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t offsets[field_count+1];
+    offsets[0] = offset;
+    for (mol_num_t i=1; i<field_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        offsets[i] = mol_unpack_number(ptr);
+        if (offsets[i-1] > offsets[i]) {
+            return MOL_ERR_OFFSET;
+        }
+    }
+    if (offsets[field_count-1] > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    offsets[field_count] = total_size;
+        mol_seg_t inner;
+        mol_errno errno;
+        inner.ptr = input->ptr + offsets[0];
+        inner.size = offsets[1] - offsets[0];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        if (offsets[2] - offsets[1] != 1) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[2];
+        inner.size = offsets[3] - offsets[2];
+        errno = MolReader_Byte32_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[3];
+        inner.size = offsets[4] - offsets[3];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+    return MOL_OK;
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_MintQuota_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t field_count = offset / 4 - 1;
+    if (field_count < 7) {
+        return MOL_ERR_FIELD_COUNT;
+    } else if (!compatible && field_count > 7) {
+        return MOL_ERR_FIELD_COUNT;
+    }
+    if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t offsets[field_count+1];
+    offsets[0] = offset;
+    for (mol_num_t i=1; i<field_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        offsets[i] = mol_unpack_number(ptr);
+        if (offsets[i-1] > offsets[i]) {
+            return MOL_ERR_OFFSET;
+        }
+    }
+    if (offsets[field_count-1] > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    offsets[field_count] = total_size;
+        mol_seg_t inner;
+        mol_errno errno;
+        inner.ptr = input->ptr + offsets[0];
+        inner.size = offsets[1] - offsets[0];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[1];
+        inner.size = offsets[2] - offsets[1];
+        errno = MolReader_Script_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[2];
+        inner.size = offsets[3] - offsets[2];
+        errno = MolReader_Uint128_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[3];
+        inner.size = offsets[4] - offsets[3];
+        errno = MolReader_Uint128_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[4];
+        inner.size = offsets[5] - offsets[4];
+        errno = MolReader_Uint64_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[5];
+        inner.size = offsets[6] - offsets[5];
+        errno = MolReader_Uint64_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[6];
+        inner.size = offsets[7] - offsets[6];
+        errno = MolReader_Uint128_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+    return MOL_OK;
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_MintQuotas_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size == MOL_NUM_T_SIZE) {
+        return MOL_OK;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t item_count = offset / 4 - 1;
+    if (input->size < MOL_NUM_T_SIZE*(item_count+1)) {
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t end;
+    for (mol_num_t i=1; i<item_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        end = mol_unpack_number(ptr);
+        if (offset > end) {
+            return MOL_ERR_OFFSET;
+        }
+        mol_seg_t inner;
+        inner.ptr = input->ptr + offset;
+        inner.size = end - offset;
+        mol_errno errno = MolReader_MintQuota_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        offset = end;
+    }
+    if (offset > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_seg_t inner;
+    inner.ptr = input->ptr + offset;
+    inner.size = total_size - offset;
+    return MolReader_MintQuota_verify(&inner, compatible);
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_XudtSupply_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t field_count = offset / 4 - 1;
+    if (field_count < 3) {
+        return MOL_ERR_FIELD_COUNT;
+    } else if (!compatible && field_count > 3) {
+        return MOL_ERR_FIELD_COUNT;
+    }
+    if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t offsets[field_count+1];
+    offsets[0] = offset;
+    for (mol_num_t i=1; i<field_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        offsets[i] = mol_unpack_number(ptr);
+        if (offsets[i-1] > offsets[i]) {
+            return MOL_ERR_OFFSET;
+        }
+    }
+    if (offsets[field_count-1] > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    offsets[field_count] = total_size;
+        mol_seg_t inner;
+        mol_errno errno;
+        inner.ptr = input->ptr + offsets[0];
+        inner.size = offsets[1] - offsets[0];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[1];
+        inner.size = offsets[2] - offsets[1];
+        errno = MolReader_Uint128_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[2];
+        inner.size = offsets[3] - offsets[2];
+        errno = MolReader_Uint128Opt_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+    return MOL_OK;
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_XudtSupplies_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size == MOL_NUM_T_SIZE) {
+        return MOL_OK;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t item_count = offset / 4 - 1;
+    if (input->size < MOL_NUM_T_SIZE*(item_count+1)) {
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t end;
+    for (mol_num_t i=1; i<item_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        end = mol_unpack_number(ptr);
+        if (offset > end) {
+            return MOL_ERR_OFFSET;
+        }
+        mol_seg_t inner;
+        inner.ptr = input->ptr + offset;
+        inner.size = end - offset;
+        mol_errno errno = MolReader_XudtSupply_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        offset = end;
+    }
+    if (offset > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_seg_t inner;
+    inner.ptr = input->ptr + offset;
+    inner.size = total_size - offset;
+    return MolReader_XudtSupply_verify(&inner, compatible);
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_ReserveAttestation_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t field_count = offset / 4 - 1;
+    if (field_count < 4) {
+        return MOL_ERR_FIELD_COUNT;
+    } else if (!compatible && field_count > 4) {
+        return MOL_ERR_FIELD_COUNT;
+    }
+    if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t offsets[field_count+1];
+    offsets[0] = offset;
+    for (mol_num_t i=1; i<field_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        offsets[i] = mol_unpack_number(ptr);
+        if (offsets[i-1] > offsets[i]) {
+            return MOL_ERR_OFFSET;
+        }
+    }
+    if (offsets[field_count-1] > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    offsets[field_count] = total_size;
+        mol_seg_t inner;
+        mol_errno errno;
+        inner.ptr = input->ptr + offsets[0];
+        inner.size = offsets[1] - offsets[0];
+        errno = MolReader_Bytes_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[1];
+        inner.size = offsets[2] - offsets[1];
+        errno = MolReader_Uint128_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[2];
+        inner.size = offsets[3] - offsets[2];
+        errno = MolReader_Uint64_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[3];
+        inner.size = offsets[4] - offsets[3];
+        errno = MolReader_Uint64_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+    return MOL_OK;
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_IdentityOpt_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size != 0) {
+        return MolReader_Bytes_verify(input, compatible);
+    } else {
+        return MOL_OK;
+    }
+}
+MOLECULE_API_DECORATOR mol_errno MolReader_OmniLockWitnessLock_verify (const mol_seg_t *input, bool compatible) {
+    if (input->size < MOL_NUM_T_SIZE) {
+        return MOL_ERR_HEADER;
+    }
+    uint8_t *ptr = input->ptr;
+    mol_num_t total_size = mol_unpack_number(ptr);
+    if (input->size != total_size) {
+        return MOL_ERR_TOTAL_SIZE;
+    }
+    if (input->size < MOL_NUM_T_SIZE * 2) {
+        return MOL_ERR_HEADER;
+    }
+    ptr += MOL_NUM_T_SIZE;
+    mol_num_t offset = mol_unpack_number(ptr);
+    if (offset % 4 > 0 || offset < MOL_NUM_T_SIZE*2) {
+        return MOL_ERR_OFFSET;
+    }
+    mol_num_t field_count = offset / 4 - 1;
+    if (field_count < 3) {
+        return MOL_ERR_FIELD_COUNT;
+    } else if (!compatible && field_count > 3) {
+        return MOL_ERR_FIELD_COUNT;
+    }
+    if (input->size < MOL_NUM_T_SIZE*(field_count+1)){
+        return MOL_ERR_HEADER;
+    }
+    mol_num_t offsets[field_count+1];
+    offsets[0] = offset;
+    for (mol_num_t i=1; i<field_count; i++) {
+        ptr += MOL_NUM_T_SIZE;
+        offsets[i] = mol_unpack_number(ptr);
+        if (offsets[i-1] > offsets[i]) {
+            return MOL_ERR_OFFSET;
+        }
+    }
+    if (offsets[field_count-1] > total_size) {
+        return MOL_ERR_OFFSET;
+    }
+    offsets[field_count] = total_size;
+        mol_seg_t inner;
+        mol_errno errno;
+        inner.ptr = input->ptr + offsets[0];
+        inner.size = offsets[1] - offsets[0];
+        errno = MolReader_BytesOpt_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[1];
+        inner.size = offsets[2] - offsets[1];
+        errno = MolReader_IdentityOpt_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+        inner.ptr = input->ptr + offsets[2];
+        inner.size = offsets[3] - offsets[2];
+        errno = MolReader_BytesOpt_verify(&inner, compatible);
+        if (errno != MOL_OK) {
+            return MOL_ERR_DATA;
+        }
+    return MOL_OK;
+}
+
+/*
+ * Builder Functions
+ */
+
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_GovernanceMembers_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 20;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[5];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[7];
+    res.seg.size += len == 0 ? 4 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[5];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[7];
+    offset += len == 0 ? 4 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[5];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[4];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[7];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_BytesVec, len);
+    } else {
+        mol_num_t of = builder.number_ptr[6];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    mol_builder_discard(builder);
+    return res;
+}
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_GovernanceMembersV1_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 12;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 32 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 32 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 32;
+        memcpy(dst, &MolDefault_Byte32, len);
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    mol_builder_discard(builder);
+    return res;
+}
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_CustodianProposal_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 16;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 36 : len;
+    len = builder.number_ptr[5];
+    res.seg.size += len == 0 ? 8 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 36 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[5];
+    offset += len == 0 ? 8 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 36;
+        memcpy(dst, &MolDefault_GovernanceMembers, len);
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[5];
+    if (len == 0) {
+        len = 8;
+        memcpy(dst, &MolDefault_Uint64, len);
+    } else {
+        mol_num_t of = builder.number_ptr[4];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    mol_builder_discard(builder);
+    return res;
+}
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_Tick_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 32;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 1 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[5];
+    res.seg.size += len == 0 ? 16 : len;
+    len = builder.number_ptr[7];
+    res.seg.size += len == 0 ? 53 : len;
+    len = builder.number_ptr[9];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[11];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[13];
+    res.seg.size += len == 0 ? 4 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 1 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[5];
+    offset += len == 0 ? 16 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[7];
+    offset += len == 0 ? 53 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[9];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[11];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[13];
+    offset += len == 0 ? 4 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
+    if (len == 0) {
+        len = 1;
+        *dst = 0;
+    } else {
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[5];
+    if (len == 0) {
+        len = 16;
+        memcpy(dst, &MolDefault_Uint128, len);
+    } else {
+        mol_num_t of = builder.number_ptr[4];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[7];
+    if (len == 0) {
+        len = 53;
+        memcpy(dst, &MolDefault_Script, len);
+    } else {
+        mol_num_t of = builder.number_ptr[6];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[9];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[8];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[11];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[10];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[13];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[12];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    mol_builder_discard(builder);
+    return res;
+}
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_TickV1_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 48;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 1 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[5];
+    res.seg.size += len == 0 ? 16 : len;
+    len = builder.number_ptr[7];
+    res.seg.size += len == 0 ? 53 : len;
+    len = builder.number_ptr[9];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[11];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[13];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[15];
+    res.seg.size += len == 0 ? 0 : len;
+    len = builder.number_ptr[17];
+    res.seg.size += len == 0 ? 1 : len;
+    len = builder.number_ptr[19];
+    res.seg.size += len == 0 ? 8 : len;
+    len = builder.number_ptr[21];
+    res.seg.size += len == 0 ? 4 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 1 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[5];
+    offset += len == 0 ? 16 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[7];
+    offset += len == 0 ? 53 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[9];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[11];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[13];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[15];
+    offset += len == 0 ? 0 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[17];
+    offset += len == 0 ? 1 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[19];
+    offset += len == 0 ? 8 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[21];
+    offset += len == 0 ? 4 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
+    if (len == 0) {
+        len = 1;
+        *dst = 0;
+    } else {
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[5];
+    if (len == 0) {
+        len = 16;
+        memcpy(dst, &MolDefault_Uint128, len);
+    } else {
+        mol_num_t of = builder.number_ptr[4];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[7];
+    if (len == 0) {
+        len = 53;
+        memcpy(dst, &MolDefault_Script, len);
+    } else {
+        mol_num_t of = builder.number_ptr[6];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[9];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[8];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[11];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[10];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[13];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[12];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[15];
+    if (len == 0) {
+        len = 0;
+        memcpy(dst, &MolDefault_Uint64Opt, len);
+    } else {
+        mol_num_t of = builder.number_ptr[14];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[17];
+    if (len == 0) {
+        len = 1;
+        *dst = 0;
+    } else {
+        mol_num_t of = builder.number_ptr[16];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[19];
+    if (len == 0) {
+        len = 8;
+        memcpy(dst, &MolDefault_Uint64, len);
+    } else {
+        mol_num_t of = builder.number_ptr[18];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[21];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[20];
+        memcpy(dst, src+of, len);
     }
     dst += len;
+    mol_builder_discard(builder);
+    return res;
+}
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_BurnPayout_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 12;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 4 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 4 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    mol_builder_discard(builder);
+    return res;
+}
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_TickReceipt_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 20;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 1 : len;
+    len = builder.number_ptr[5];
+    res.seg.size += len == 0 ? 32 : len;
+    len = builder.number_ptr[7];
+    res.seg.size += len == 0 ? 4 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 1 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
     len = builder.number_ptr[5];
+    offset += len == 0 ? 32 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[7];
+    offset += len == 0 ? 4 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
     if (len == 0) {
         len = 4;
         memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 1;
+        *dst = 0;
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[5];
+    if (len == 0) {
+        len = 32;
+        memcpy(dst, &MolDefault_Byte32, len);
     } else {
         mol_num_t of = builder.number_ptr[4];
         memcpy(dst, src+of, len);
@@ -454,7 +1824,7 @@ MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_GovernanceMembers_build (mol_bui
     len = builder.number_ptr[7];
     if (len == 0) {
         len = 4;
-        memcpy(dst, &MolDefault_BytesVec, len);
+        memcpy(dst, &MolDefault_Bytes, len);
     } else {
         mol_num_t of = builder.number_ptr[6];
         memcpy(dst, src+of, len);
@@ -463,26 +1833,26 @@ MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_GovernanceMembers_build (mol_bui
     mol_builder_discard(builder);
     return res;
 }
-MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_Tick_build (mol_builder_t builder) {
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_MintQuota_build (mol_builder_t builder) {
     mol_seg_res_t res;
     res.errno = MOL_OK;
     mol_num_t offset = 32;
     mol_num_t len;
     res.seg.size = offset;
     len = builder.number_ptr[1];
-    res.seg.size += len == 0 ? 1 : len;
-    len = builder.number_ptr[3];
     res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 53 : len;
     len = builder.number_ptr[5];
     res.seg.size += len == 0 ? 16 : len;
     len = builder.number_ptr[7];
-    res.seg.size += len == 0 ? 53 : len;
+    res.seg.size += len == 0 ? 16 : len;
     len = builder.number_ptr[9];
-    res.seg.size += len == 0 ? 4 : len;
+    res.seg.size += len == 0 ? 8 : len;
     len = builder.number_ptr[11];
-    res.seg.size += len == 0 ? 4 : len;
+    res.seg.size += len == 0 ? 8 : len;
     len = builder.number_ptr[13];
-    res.seg.size += len == 0 ? 4 : len;
+    res.seg.size += len == 0 ? 16 : len;
     res.seg.ptr = (uint8_t*)malloc(res.seg.size);
     uint8_t *dst = res.seg.ptr;
     mol_pack_number(dst, &res.seg.size);
@@ -490,11 +1860,11 @@ MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_Tick_build (mol_builder_t builde
     mol_pack_number(dst, &offset);
     dst += MOL_NUM_T_SIZE;
     len = builder.number_ptr[1];
-    offset += len == 0 ? 1 : len;
+    offset += len == 0 ? 4 : len;
     mol_pack_number(dst, &offset);
     dst += MOL_NUM_T_SIZE;
     len = builder.number_ptr[3];
-    offset += len == 0 ? 4 : len;
+    offset += len == 0 ? 53 : len;
     mol_pack_number(dst, &offset);
     dst += MOL_NUM_T_SIZE;
     len = builder.number_ptr[5];
@@ -502,24 +1872,24 @@ MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_Tick_build (mol_builder_t builde
     mol_pack_number(dst, &offset);
     dst += MOL_NUM_T_SIZE;
     len = builder.number_ptr[7];
-    offset += len == 0 ? 53 : len;
+    offset += len == 0 ? 16 : len;
     mol_pack_number(dst, &offset);
     dst += MOL_NUM_T_SIZE;
     len = builder.number_ptr[9];
-    offset += len == 0 ? 4 : len;
+    offset += len == 0 ? 8 : len;
     mol_pack_number(dst, &offset);
     dst += MOL_NUM_T_SIZE;
     len = builder.number_ptr[11];
-    offset += len == 0 ? 4 : len;
+    offset += len == 0 ? 8 : len;
     mol_pack_number(dst, &offset);
     dst += MOL_NUM_T_SIZE;
     len = builder.number_ptr[13];
-    offset += len == 0 ? 4 : len;
+    offset += len == 0 ? 16 : len;
     uint8_t *src = builder.data_ptr;
     len = builder.number_ptr[1];
     if (len == 0) {
-        len = 1;
-        *dst = 0;
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
     } else {
         mol_num_t of = builder.number_ptr[0];
         memcpy(dst, src+of, len);
@@ -527,8 +1897,8 @@ MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_Tick_build (mol_builder_t builde
     dst += len;
     len = builder.number_ptr[3];
     if (len == 0) {
-        len = 4;
-        memcpy(dst, &MolDefault_Bytes, len);
+        len = 53;
+        memcpy(dst, &MolDefault_Script, len);
     } else {
         mol_num_t of = builder.number_ptr[2];
         memcpy(dst, src+of, len);
@@ -545,8 +1915,8 @@ MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_Tick_build (mol_builder_t builde
     dst += len;
     len = builder.number_ptr[7];
     if (len == 0) {
-        len = 53;
-        memcpy(dst, &MolDefault_Script, len);
+        len = 16;
+        memcpy(dst, &MolDefault_Uint128, len);
     } else {
         mol_num_t of = builder.number_ptr[6];
         memcpy(dst, src+of, len);
@@ -554,8 +1924,8 @@ MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_Tick_build (mol_builder_t builde
     dst += len;
     len = builder.number_ptr[9];
     if (len == 0) {
-        len = 4;
-        memcpy(dst, &MolDefault_Bytes, len);
+        len = 8;
+        memcpy(dst, &MolDefault_Uint64, len);
     } else {
         mol_num_t of = builder.number_ptr[8];
         memcpy(dst, src+of, len);
@@ -563,19 +1933,152 @@ MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_Tick_build (mol_builder_t builde
     dst += len;
     len = builder.number_ptr[11];
     if (len == 0) {
-        len = 4;
-        memcpy(dst, &MolDefault_Bytes, len);
+        len = 8;
+        memcpy(dst, &MolDefault_Uint64, len);
     } else {
         mol_num_t of = builder.number_ptr[10];
         memcpy(dst, src+of, len);
     }
     dst += len;
     len = builder.number_ptr[13];
+    if (len == 0) {
+        len = 16;
+        memcpy(dst, &MolDefault_Uint128, len);
+    } else {
+        mol_num_t of = builder.number_ptr[12];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    mol_builder_discard(builder);
+    return res;
+}
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_XudtSupply_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 16;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 16 : len;
+    len = builder.number_ptr[5];
+    res.seg.size += len == 0 ? 0 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 16 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[5];
+    offset += len == 0 ? 0 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
     if (len == 0) {
         len = 4;
         memcpy(dst, &MolDefault_Bytes, len);
     } else {
-        mol_num_t of = builder.number_ptr[12];
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 16;
+        memcpy(dst, &MolDefault_Uint128, len);
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[5];
+    if (len == 0) {
+        len = 0;
+        memcpy(dst, &MolDefault_Uint128Opt, len);
+    } else {
+        mol_num_t of = builder.number_ptr[4];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    mol_builder_discard(builder);
+    return res;
+}
+MOLECULE_API_DECORATOR mol_seg_res_t MolBuilder_ReserveAttestation_build (mol_builder_t builder) {
+    mol_seg_res_t res;
+    res.errno = MOL_OK;
+    mol_num_t offset = 20;
+    mol_num_t len;
+    res.seg.size = offset;
+    len = builder.number_ptr[1];
+    res.seg.size += len == 0 ? 4 : len;
+    len = builder.number_ptr[3];
+    res.seg.size += len == 0 ? 16 : len;
+    len = builder.number_ptr[5];
+    res.seg.size += len == 0 ? 8 : len;
+    len = builder.number_ptr[7];
+    res.seg.size += len == 0 ? 8 : len;
+    res.seg.ptr = (uint8_t*)malloc(res.seg.size);
+    uint8_t *dst = res.seg.ptr;
+    mol_pack_number(dst, &res.seg.size);
+    dst += MOL_NUM_T_SIZE;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[1];
+    offset += len == 0 ? 4 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[3];
+    offset += len == 0 ? 16 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[5];
+    offset += len == 0 ? 8 : len;
+    mol_pack_number(dst, &offset);
+    dst += MOL_NUM_T_SIZE;
+    len = builder.number_ptr[7];
+    offset += len == 0 ? 8 : len;
+    uint8_t *src = builder.data_ptr;
+    len = builder.number_ptr[1];
+    if (len == 0) {
+        len = 4;
+        memcpy(dst, &MolDefault_Bytes, len);
+    } else {
+        mol_num_t of = builder.number_ptr[0];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[3];
+    if (len == 0) {
+        len = 16;
+        memcpy(dst, &MolDefault_Uint128, len);
+    } else {
+        mol_num_t of = builder.number_ptr[2];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[5];
+    if (len == 0) {
+        len = 8;
+        memcpy(dst, &MolDefault_Uint64, len);
+    } else {
+        mol_num_t of = builder.number_ptr[4];
+        memcpy(dst, src+of, len);
+    }
+    dst += len;
+    len = builder.number_ptr[7];
+    if (len == 0) {
+        len = 8;
+        memcpy(dst, &MolDefault_Uint64, len);
+    } else {
+        mol_num_t of = builder.number_ptr[6];
         memcpy(dst, src+of, len);
     }
     dst += len;
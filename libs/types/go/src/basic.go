@@ -678,6 +678,156 @@ t.Nth15(*s.Nth15())
 }
         
 
+type Uint64OptBuilder struct {
+	isNone bool
+	inner  Uint64
+}
+func NewUint64OptBuilder() *Uint64OptBuilder {
+	return &Uint64OptBuilder{isNone: true, inner: Uint64Default()}
+}
+func (s *Uint64OptBuilder) Set(v Uint64) *Uint64OptBuilder {
+	s.isNone = false
+	s.inner = v
+	return s
+}
+func (s *Uint64OptBuilder) Build() Uint64Opt {
+	var ret Uint64Opt
+	if s.isNone {
+		ret = Uint64Opt{inner: []byte{}}
+	} else {
+		ret = Uint64Opt{inner: s.inner.AsSlice()}
+	}
+	return ret
+}
+            
+
+type Uint64Opt struct {
+    inner []byte
+}
+        
+
+func Uint64OptFromSliceUnchecked(slice []byte) *Uint64Opt {
+    return &Uint64Opt{inner: slice}
+}
+func (s *Uint64Opt) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func Uint64OptDefault() Uint64Opt {
+    return *Uint64OptFromSliceUnchecked([]byte{  })
+}
+            
+
+func Uint64OptFromSlice(slice []byte, compatible bool) (*Uint64Opt, error) {
+    if len(slice) == 0 {
+        return &Uint64Opt{inner: slice}, nil
+    }
+
+    _, err := Uint64FromSlice(slice, compatible)
+    if err != nil {
+        return nil, err
+    }
+    return &Uint64Opt{inner: slice}, nil
+}
+            
+
+func (s *Uint64Opt) IntoUint64() (*Uint64, error) {
+	if s.IsNone() {
+		return nil, errors.New("No data")
+	}
+	return Uint64FromSliceUnchecked(s.AsSlice()), nil
+}
+func (s *Uint64Opt) IsSome() bool {
+    return len(s.inner) != 0
+}
+func (s *Uint64Opt) IsNone() bool {
+    return len(s.inner) == 0
+}
+func (s *Uint64Opt) AsBuilder() Uint64OptBuilder {
+    var ret = NewUint64OptBuilder()
+    if s.IsSome() {
+        ret.Set(*Uint64FromSliceUnchecked(s.AsSlice()))
+    }
+    return *ret
+}
+            
+
+type Uint128OptBuilder struct {
+	isNone bool
+	inner  Uint128
+}
+func NewUint128OptBuilder() *Uint128OptBuilder {
+	return &Uint128OptBuilder{isNone: true, inner: Uint128Default()}
+}
+func (s *Uint128OptBuilder) Set(v Uint128) *Uint128OptBuilder {
+	s.isNone = false
+	s.inner = v
+	return s
+}
+func (s *Uint128OptBuilder) Build() Uint128Opt {
+	var ret Uint128Opt
+	if s.isNone {
+		ret = Uint128Opt{inner: []byte{}}
+	} else {
+		ret = Uint128Opt{inner: s.inner.AsSlice()}
+	}
+	return ret
+}
+            
+
+type Uint128Opt struct {
+    inner []byte
+}
+        
+
+func Uint128OptFromSliceUnchecked(slice []byte) *Uint128Opt {
+    return &Uint128Opt{inner: slice}
+}
+func (s *Uint128Opt) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func Uint128OptDefault() Uint128Opt {
+    return *Uint128OptFromSliceUnchecked([]byte{  })
+}
+            
+
+func Uint128OptFromSlice(slice []byte, compatible bool) (*Uint128Opt, error) {
+    if len(slice) == 0 {
+        return &Uint128Opt{inner: slice}, nil
+    }
+
+    _, err := Uint128FromSlice(slice, compatible)
+    if err != nil {
+        return nil, err
+    }
+    return &Uint128Opt{inner: slice}, nil
+}
+            
+
+func (s *Uint128Opt) IntoUint128() (*Uint128, error) {
+	if s.IsNone() {
+		return nil, errors.New("No data")
+	}
+	return Uint128FromSliceUnchecked(s.AsSlice()), nil
+}
+func (s *Uint128Opt) IsSome() bool {
+    return len(s.inner) != 0
+}
+func (s *Uint128Opt) IsNone() bool {
+    return len(s.inner) == 0
+}
+func (s *Uint128Opt) AsBuilder() Uint128OptBuilder {
+    var ret = NewUint128OptBuilder()
+    if s.IsSome() {
+        ret.Set(*Uint128FromSliceUnchecked(s.AsSlice()))
+    }
+    return *ret
+}
+            
+
 type BytesBuilder struct {
     inner []Byte
 }
@@ -239,153 +239,2514 @@ func (s *GovernanceMembers) AsBuilder() GovernanceMembersBuilder {
 }
         
 
+type GovernanceMembersV1Builder struct {
+    parent_id Bytes
+members_root Byte32
+}
+        
+
+func (s *GovernanceMembersV1Builder) Build() GovernanceMembersV1 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (2 + 1)
+    offsets := make([]uint32, 0, 2)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.parent_id.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.members_root.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.parent_id.AsSlice())
+b.Write(s.members_root.AsSlice())
+    return GovernanceMembersV1{inner: b.Bytes()}
+}
+                
+
+func (s *GovernanceMembersV1Builder) ParentId(v Bytes) *GovernanceMembersV1Builder {
+    s.parent_id = v
+    return s
+}
+            
+
+func (s *GovernanceMembersV1Builder) MembersRoot(v Byte32) *GovernanceMembersV1Builder {
+    s.members_root = v
+    return s
+}
+            
+
+func NewGovernanceMembersV1Builder() *GovernanceMembersV1Builder {
+	return &GovernanceMembersV1Builder{ parent_id: BytesDefault(),members_root: Byte32Default() }
+}
+    
+
+type GovernanceMembersV1 struct {
+    inner []byte
+}
+        
+
+func GovernanceMembersV1FromSliceUnchecked(slice []byte) *GovernanceMembersV1 {
+    return &GovernanceMembersV1{inner: slice}
+}
+func (s *GovernanceMembersV1) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func GovernanceMembersV1Default() GovernanceMembersV1 {
+    return *GovernanceMembersV1FromSliceUnchecked([]byte{ 48,0,0,0,12,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func GovernanceMembersV1FromSlice(slice []byte, compatible bool) (*GovernanceMembersV1, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "GovernanceMembersV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "GovernanceMembersV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "GovernanceMembersV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "GovernanceMembersV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "GovernanceMembersV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 2 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 2 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Byte32FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &GovernanceMembersV1{inner: slice}, nil
+}
+            
+
+func (s *GovernanceMembersV1) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *GovernanceMembersV1) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *GovernanceMembersV1) Len() uint {
+    return s.FieldCount()
+}
+func (s *GovernanceMembersV1) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *GovernanceMembersV1) CountExtraFields() uint {
+    return s.FieldCount() - 2
+}
+
+func (s *GovernanceMembersV1) HasExtraFields() bool {
+    return 2 != s.FieldCount()
+}
+            
+
+func (s *GovernanceMembersV1) ParentId() *Bytes {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *GovernanceMembersV1) MembersRoot() *Byte32 {
+    var ret *Byte32
+    start := unpackNumber(s.inner[8:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[12:])
+        ret = Byte32FromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = Byte32FromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *GovernanceMembersV1) AsBuilder() GovernanceMembersV1Builder {
+    ret := NewGovernanceMembersV1Builder().ParentId(*s.ParentId()).MembersRoot(*s.MembersRoot())
+    return *ret
+}
+        
+
+type CustodianProposalBuilder struct {
+    custodian_id Bytes
+members GovernanceMembers
+activation_epoch Uint64
+}
+        
+
+func (s *CustodianProposalBuilder) Build() CustodianProposal {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (3 + 1)
+    offsets := make([]uint32, 0, 3)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.custodian_id.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.members.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.activation_epoch.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.custodian_id.AsSlice())
+b.Write(s.members.AsSlice())
+b.Write(s.activation_epoch.AsSlice())
+    return CustodianProposal{inner: b.Bytes()}
+}
+                
+
+func (s *CustodianProposalBuilder) CustodianId(v Bytes) *CustodianProposalBuilder {
+    s.custodian_id = v
+    return s
+}
+            
+
+func (s *CustodianProposalBuilder) Members(v GovernanceMembers) *CustodianProposalBuilder {
+    s.members = v
+    return s
+}
+            
+
+func (s *CustodianProposalBuilder) ActivationEpoch(v Uint64) *CustodianProposalBuilder {
+    s.activation_epoch = v
+    return s
+}
+            
+
+func NewCustodianProposalBuilder() *CustodianProposalBuilder {
+	return &CustodianProposalBuilder{ custodian_id: BytesDefault(),members: GovernanceMembersDefault(),activation_epoch: Uint64Default() }
+}
+    
+
+type CustodianProposal struct {
+    inner []byte
+}
+        
+
+func CustodianProposalFromSliceUnchecked(slice []byte) *CustodianProposal {
+    return &CustodianProposal{inner: slice}
+}
+func (s *CustodianProposal) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func CustodianProposalDefault() CustodianProposal {
+    return *CustodianProposalFromSliceUnchecked([]byte{ 64,0,0,0,16,0,0,0,20,0,0,0,56,0,0,0,0,0,0,0,36,0,0,0,20,0,0,0,24,0,0,0,28,0,0,0,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,4,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func CustodianProposalFromSlice(slice []byte, compatible bool) (*CustodianProposal, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "CustodianProposal", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "CustodianProposal", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "CustodianProposal", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "CustodianProposal", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "CustodianProposal", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = GovernanceMembersFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &CustodianProposal{inner: slice}, nil
+}
+            
+
+func (s *CustodianProposal) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *CustodianProposal) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *CustodianProposal) Len() uint {
+    return s.FieldCount()
+}
+func (s *CustodianProposal) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *CustodianProposal) CountExtraFields() uint {
+    return s.FieldCount() - 3
+}
+
+func (s *CustodianProposal) HasExtraFields() bool {
+    return 3 != s.FieldCount()
+}
+            
+
+func (s *CustodianProposal) CustodianId() *Bytes {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *CustodianProposal) Members() *GovernanceMembers {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return GovernanceMembersFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *CustodianProposal) ActivationEpoch() *Uint64 {
+    var ret *Uint64
+    start := unpackNumber(s.inner[12:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[16:])
+        ret = Uint64FromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = Uint64FromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *CustodianProposal) AsBuilder() CustodianProposalBuilder {
+    ret := NewCustodianProposalBuilder().CustodianId(*s.CustodianId()).Members(*s.Members()).ActivationEpoch(*s.ActivationEpoch())
+    return *ret
+}
+        
+
 type TickBuilder struct {
     tick_type Byte
 token_id Bytes
 value Uint128
 merchant Script
-coin_type Bytes
-tx_hash Bytes
-receipt_addr Bytes
+coin_type Bytes
+tx_hash Bytes
+receipt_addr Bytes
+}
+        
+
+func (s *TickBuilder) Build() Tick {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (7 + 1)
+    offsets := make([]uint32, 0, 7)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.tick_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.token_id.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.value.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.merchant.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.coin_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.tx_hash.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.receipt_addr.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.tick_type.AsSlice())
+b.Write(s.token_id.AsSlice())
+b.Write(s.value.AsSlice())
+b.Write(s.merchant.AsSlice())
+b.Write(s.coin_type.AsSlice())
+b.Write(s.tx_hash.AsSlice())
+b.Write(s.receipt_addr.AsSlice())
+    return Tick{inner: b.Bytes()}
+}
+                
+
+func (s *TickBuilder) TickType(v Byte) *TickBuilder {
+    s.tick_type = v
+    return s
+}
+            
+
+func (s *TickBuilder) TokenId(v Bytes) *TickBuilder {
+    s.token_id = v
+    return s
+}
+            
+
+func (s *TickBuilder) Value(v Uint128) *TickBuilder {
+    s.value = v
+    return s
+}
+            
+
+func (s *TickBuilder) Merchant(v Script) *TickBuilder {
+    s.merchant = v
+    return s
+}
+            
+
+func (s *TickBuilder) CoinType(v Bytes) *TickBuilder {
+    s.coin_type = v
+    return s
+}
+            
+
+func (s *TickBuilder) TxHash(v Bytes) *TickBuilder {
+    s.tx_hash = v
+    return s
+}
+            
+
+func (s *TickBuilder) ReceiptAddr(v Bytes) *TickBuilder {
+    s.receipt_addr = v
+    return s
+}
+            
+
+func NewTickBuilder() *TickBuilder {
+	return &TickBuilder{ tick_type: ByteDefault(),token_id: BytesDefault(),value: Uint128Default(),merchant: ScriptDefault(),coin_type: BytesDefault(),tx_hash: BytesDefault(),receipt_addr: BytesDefault() }
+}
+    
+
+type Tick struct {
+    inner []byte
+}
+        
+
+func TickFromSliceUnchecked(slice []byte) *Tick {
+    return &Tick{inner: slice}
+}
+func (s *Tick) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func TickDefault() Tick {
+    return *TickFromSliceUnchecked([]byte{ 118,0,0,0,32,0,0,0,33,0,0,0,37,0,0,0,53,0,0,0,106,0,0,0,110,0,0,0,114,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func TickFromSlice(slice []byte, compatible bool) (*Tick, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "Tick", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "Tick", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "Tick", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "Tick", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "Tick", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 7 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 7 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint128FromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ScriptFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[4]:offsets[5]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[5]:offsets[6]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[6]:offsets[7]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &Tick{inner: slice}, nil
+}
+            
+
+func (s *Tick) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *Tick) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *Tick) Len() uint {
+    return s.FieldCount()
+}
+func (s *Tick) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *Tick) CountExtraFields() uint {
+    return s.FieldCount() - 7
+}
+
+func (s *Tick) HasExtraFields() bool {
+    return 7 != s.FieldCount()
+}
+            
+
+func (s *Tick) TickType() *Byte {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return ByteFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *Tick) TokenId() *Bytes {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *Tick) Value() *Uint128 {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return Uint128FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *Tick) Merchant() *Script {
+    start := unpackNumber(s.inner[16:])
+    end := unpackNumber(s.inner[20:])
+    return ScriptFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *Tick) CoinType() *Bytes {
+    start := unpackNumber(s.inner[20:])
+    end := unpackNumber(s.inner[24:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *Tick) TxHash() *Bytes {
+    start := unpackNumber(s.inner[24:])
+    end := unpackNumber(s.inner[28:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *Tick) ReceiptAddr() *Bytes {
+    var ret *Bytes
+    start := unpackNumber(s.inner[28:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[32:])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = BytesFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *Tick) AsBuilder() TickBuilder {
+    ret := NewTickBuilder().TickType(*s.TickType()).TokenId(*s.TokenId()).Value(*s.Value()).Merchant(*s.Merchant()).CoinType(*s.CoinType()).TxHash(*s.TxHash()).ReceiptAddr(*s.ReceiptAddr())
+    return *ret
+}
+        
+
+type TickV1Builder struct {
+    tick_type Byte
+token_id Bytes
+value Uint128
+merchant Script
+coin_type Bytes
+tx_hash Bytes
+receipt_addr Bytes
+deadline Uint64Opt
+escrowed Byte
+nonce Uint64
+memo Bytes
+}
+        
+
+func (s *TickV1Builder) Build() TickV1 {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (11 + 1)
+    offsets := make([]uint32, 0, 11)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.tick_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.token_id.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.value.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.merchant.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.coin_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.tx_hash.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.receipt_addr.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.deadline.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.escrowed.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.nonce.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.memo.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.tick_type.AsSlice())
+b.Write(s.token_id.AsSlice())
+b.Write(s.value.AsSlice())
+b.Write(s.merchant.AsSlice())
+b.Write(s.coin_type.AsSlice())
+b.Write(s.tx_hash.AsSlice())
+b.Write(s.receipt_addr.AsSlice())
+b.Write(s.deadline.AsSlice())
+b.Write(s.escrowed.AsSlice())
+b.Write(s.nonce.AsSlice())
+b.Write(s.memo.AsSlice())
+    return TickV1{inner: b.Bytes()}
+}
+                
+
+func (s *TickV1Builder) TickType(v Byte) *TickV1Builder {
+    s.tick_type = v
+    return s
+}
+            
+
+func (s *TickV1Builder) TokenId(v Bytes) *TickV1Builder {
+    s.token_id = v
+    return s
+}
+            
+
+func (s *TickV1Builder) Value(v Uint128) *TickV1Builder {
+    s.value = v
+    return s
+}
+            
+
+func (s *TickV1Builder) Merchant(v Script) *TickV1Builder {
+    s.merchant = v
+    return s
+}
+            
+
+func (s *TickV1Builder) CoinType(v Bytes) *TickV1Builder {
+    s.coin_type = v
+    return s
+}
+            
+
+func (s *TickV1Builder) TxHash(v Bytes) *TickV1Builder {
+    s.tx_hash = v
+    return s
+}
+            
+
+func (s *TickV1Builder) ReceiptAddr(v Bytes) *TickV1Builder {
+    s.receipt_addr = v
+    return s
+}
+            
+
+func (s *TickV1Builder) Deadline(v Uint64Opt) *TickV1Builder {
+    s.deadline = v
+    return s
+}
+            
+
+func (s *TickV1Builder) Escrowed(v Byte) *TickV1Builder {
+    s.escrowed = v
+    return s
+}
+            
+
+func (s *TickV1Builder) Nonce(v Uint64) *TickV1Builder {
+    s.nonce = v
+    return s
+}
+            
+
+func (s *TickV1Builder) Memo(v Bytes) *TickV1Builder {
+    s.memo = v
+    return s
+}
+            
+
+func NewTickV1Builder() *TickV1Builder {
+	return &TickV1Builder{ tick_type: ByteDefault(),token_id: BytesDefault(),value: Uint128Default(),merchant: ScriptDefault(),coin_type: BytesDefault(),tx_hash: BytesDefault(),receipt_addr: BytesDefault(),deadline: Uint64OptDefault(),escrowed: ByteDefault(),nonce: Uint64Default(),memo: BytesDefault() }
+}
+    
+
+type TickV1 struct {
+    inner []byte
+}
+        
+
+func TickV1FromSliceUnchecked(slice []byte) *TickV1 {
+    return &TickV1{inner: slice}
+}
+func (s *TickV1) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func TickV1Default() TickV1 {
+    return *TickV1FromSliceUnchecked([]byte{ 147,0,0,0,48,0,0,0,49,0,0,0,53,0,0,0,69,0,0,0,122,0,0,0,126,0,0,0,130,0,0,0,134,0,0,0,134,0,0,0,135,0,0,0,143,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func TickV1FromSlice(slice []byte, compatible bool) (*TickV1, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "TickV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "TickV1", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "TickV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "TickV1", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "TickV1", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 11 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 11 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint128FromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ScriptFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[4]:offsets[5]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[5]:offsets[6]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[6]:offsets[7]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64OptFromSlice(slice[offsets[7]:offsets[8]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ByteFromSlice(slice[offsets[8]:offsets[9]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[9]:offsets[10]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[10]:offsets[11]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &TickV1{inner: slice}, nil
+}
+            
+
+func (s *TickV1) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *TickV1) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *TickV1) Len() uint {
+    return s.FieldCount()
+}
+func (s *TickV1) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *TickV1) CountExtraFields() uint {
+    return s.FieldCount() - 11
+}
+
+func (s *TickV1) HasExtraFields() bool {
+    return 11 != s.FieldCount()
+}
+            
+
+func (s *TickV1) TickType() *Byte {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return ByteFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) TokenId() *Bytes {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) Value() *Uint128 {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return Uint128FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) Merchant() *Script {
+    start := unpackNumber(s.inner[16:])
+    end := unpackNumber(s.inner[20:])
+    return ScriptFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) CoinType() *Bytes {
+    start := unpackNumber(s.inner[20:])
+    end := unpackNumber(s.inner[24:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) TxHash() *Bytes {
+    start := unpackNumber(s.inner[24:])
+    end := unpackNumber(s.inner[28:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) ReceiptAddr() *Bytes {
+    start := unpackNumber(s.inner[28:])
+    end := unpackNumber(s.inner[32:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) Deadline() *Uint64Opt {
+    start := unpackNumber(s.inner[32:])
+    end := unpackNumber(s.inner[36:])
+    return Uint64OptFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) Escrowed() *Byte {
+    start := unpackNumber(s.inner[36:])
+    end := unpackNumber(s.inner[40:])
+    return ByteFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) Nonce() *Uint64 {
+    start := unpackNumber(s.inner[40:])
+    end := unpackNumber(s.inner[44:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickV1) Memo() *Bytes {
+    var ret *Bytes
+    start := unpackNumber(s.inner[44:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[48:])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = BytesFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *TickV1) AsBuilder() TickV1Builder {
+    ret := NewTickV1Builder().TickType(*s.TickType()).TokenId(*s.TokenId()).Value(*s.Value()).Merchant(*s.Merchant()).CoinType(*s.CoinType()).TxHash(*s.TxHash()).ReceiptAddr(*s.ReceiptAddr()).Deadline(*s.Deadline()).Escrowed(*s.Escrowed()).Nonce(*s.Nonce()).Memo(*s.Memo())
+    return *ret
+}
+        
+
+type BurnPayoutBuilder struct {
+    coin_type Bytes
+tx_hash Bytes
+}
+        
+
+func (s *BurnPayoutBuilder) Build() BurnPayout {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (2 + 1)
+    offsets := make([]uint32, 0, 2)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.coin_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.tx_hash.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.coin_type.AsSlice())
+b.Write(s.tx_hash.AsSlice())
+    return BurnPayout{inner: b.Bytes()}
+}
+                
+
+func (s *BurnPayoutBuilder) CoinType(v Bytes) *BurnPayoutBuilder {
+    s.coin_type = v
+    return s
+}
+            
+
+func (s *BurnPayoutBuilder) TxHash(v Bytes) *BurnPayoutBuilder {
+    s.tx_hash = v
+    return s
+}
+            
+
+func NewBurnPayoutBuilder() *BurnPayoutBuilder {
+	return &BurnPayoutBuilder{ coin_type: BytesDefault(),tx_hash: BytesDefault() }
+}
+    
+
+type BurnPayout struct {
+    inner []byte
+}
+        
+
+func BurnPayoutFromSliceUnchecked(slice []byte) *BurnPayout {
+    return &BurnPayout{inner: slice}
+}
+func (s *BurnPayout) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func BurnPayoutDefault() BurnPayout {
+    return *BurnPayoutFromSliceUnchecked([]byte{ 20,0,0,0,12,0,0,0,16,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func BurnPayoutFromSlice(slice []byte, compatible bool) (*BurnPayout, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "BurnPayout", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "BurnPayout", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "BurnPayout", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "BurnPayout", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "BurnPayout", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 2 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 2 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &BurnPayout{inner: slice}, nil
+}
+            
+
+func (s *BurnPayout) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *BurnPayout) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *BurnPayout) Len() uint {
+    return s.FieldCount()
+}
+func (s *BurnPayout) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *BurnPayout) CountExtraFields() uint {
+    return s.FieldCount() - 2
+}
+
+func (s *BurnPayout) HasExtraFields() bool {
+    return 2 != s.FieldCount()
+}
+            
+
+func (s *BurnPayout) CoinType() *Bytes {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *BurnPayout) TxHash() *Bytes {
+    var ret *Bytes
+    start := unpackNumber(s.inner[8:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[12:])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = BytesFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *BurnPayout) AsBuilder() BurnPayoutBuilder {
+    ret := NewBurnPayoutBuilder().CoinType(*s.CoinType()).TxHash(*s.TxHash())
+    return *ret
+}
+        
+
+type TickReceiptBuilder struct {
+    tick Bytes
+outcome Byte
+custodian_lock_hash Byte32
+settlement_tx_hash Bytes
+}
+        
+
+func (s *TickReceiptBuilder) Build() TickReceipt {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (4 + 1)
+    offsets := make([]uint32, 0, 4)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.tick.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.outcome.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.custodian_lock_hash.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.settlement_tx_hash.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.tick.AsSlice())
+b.Write(s.outcome.AsSlice())
+b.Write(s.custodian_lock_hash.AsSlice())
+b.Write(s.settlement_tx_hash.AsSlice())
+    return TickReceipt{inner: b.Bytes()}
+}
+                
+
+func (s *TickReceiptBuilder) Tick(v Bytes) *TickReceiptBuilder {
+    s.tick = v
+    return s
+}
+            
+
+func (s *TickReceiptBuilder) Outcome(v Byte) *TickReceiptBuilder {
+    s.outcome = v
+    return s
+}
+            
+
+func (s *TickReceiptBuilder) CustodianLockHash(v Byte32) *TickReceiptBuilder {
+    s.custodian_lock_hash = v
+    return s
+}
+            
+
+func (s *TickReceiptBuilder) SettlementTxHash(v Bytes) *TickReceiptBuilder {
+    s.settlement_tx_hash = v
+    return s
+}
+            
+
+func NewTickReceiptBuilder() *TickReceiptBuilder {
+	return &TickReceiptBuilder{ tick: BytesDefault(),outcome: ByteDefault(),custodian_lock_hash: Byte32Default(),settlement_tx_hash: BytesDefault() }
+}
+    
+
+type TickReceipt struct {
+    inner []byte
+}
+        
+
+func TickReceiptFromSliceUnchecked(slice []byte) *TickReceipt {
+    return &TickReceipt{inner: slice}
+}
+func (s *TickReceipt) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func TickReceiptDefault() TickReceipt {
+    return *TickReceiptFromSliceUnchecked([]byte{ 61,0,0,0,20,0,0,0,24,0,0,0,25,0,0,0,57,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func TickReceiptFromSlice(slice []byte, compatible bool) (*TickReceipt, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "TickReceipt", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "TickReceipt", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "TickReceipt", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "TickReceipt", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "TickReceipt", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 4 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 4 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ByteFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Byte32FromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = BytesFromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &TickReceipt{inner: slice}, nil
+}
+            
+
+func (s *TickReceipt) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *TickReceipt) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *TickReceipt) Len() uint {
+    return s.FieldCount()
+}
+func (s *TickReceipt) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *TickReceipt) CountExtraFields() uint {
+    return s.FieldCount() - 4
+}
+
+func (s *TickReceipt) HasExtraFields() bool {
+    return 4 != s.FieldCount()
+}
+            
+
+func (s *TickReceipt) Tick() *Bytes {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickReceipt) Outcome() *Byte {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return ByteFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickReceipt) CustodianLockHash() *Byte32 {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return Byte32FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *TickReceipt) SettlementTxHash() *Bytes {
+    var ret *Bytes
+    start := unpackNumber(s.inner[16:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[20:])
+        ret = BytesFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = BytesFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *TickReceipt) AsBuilder() TickReceiptBuilder {
+    ret := NewTickReceiptBuilder().Tick(*s.Tick()).Outcome(*s.Outcome()).CustodianLockHash(*s.CustodianLockHash()).SettlementTxHash(*s.SettlementTxHash())
+    return *ret
+}
+        
+
+type MintQuotaBuilder struct {
+    token_id Bytes
+merchant Script
+max_request_value Uint128
+cap Uint128
+period Uint64
+period_start Uint64
+minted Uint128
+}
+        
+
+func (s *MintQuotaBuilder) Build() MintQuota {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (7 + 1)
+    offsets := make([]uint32, 0, 7)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.token_id.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.merchant.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.max_request_value.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.cap.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.period.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.period_start.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.minted.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.token_id.AsSlice())
+b.Write(s.merchant.AsSlice())
+b.Write(s.max_request_value.AsSlice())
+b.Write(s.cap.AsSlice())
+b.Write(s.period.AsSlice())
+b.Write(s.period_start.AsSlice())
+b.Write(s.minted.AsSlice())
+    return MintQuota{inner: b.Bytes()}
+}
+                
+
+func (s *MintQuotaBuilder) TokenId(v Bytes) *MintQuotaBuilder {
+    s.token_id = v
+    return s
+}
+            
+
+func (s *MintQuotaBuilder) Merchant(v Script) *MintQuotaBuilder {
+    s.merchant = v
+    return s
+}
+            
+
+func (s *MintQuotaBuilder) MaxRequestValue(v Uint128) *MintQuotaBuilder {
+    s.max_request_value = v
+    return s
+}
+            
+
+func (s *MintQuotaBuilder) Cap(v Uint128) *MintQuotaBuilder {
+    s.cap = v
+    return s
+}
+            
+
+func (s *MintQuotaBuilder) Period(v Uint64) *MintQuotaBuilder {
+    s.period = v
+    return s
+}
+            
+
+func (s *MintQuotaBuilder) PeriodStart(v Uint64) *MintQuotaBuilder {
+    s.period_start = v
+    return s
+}
+            
+
+func (s *MintQuotaBuilder) Minted(v Uint128) *MintQuotaBuilder {
+    s.minted = v
+    return s
+}
+            
+
+func NewMintQuotaBuilder() *MintQuotaBuilder {
+	return &MintQuotaBuilder{ token_id: BytesDefault(),merchant: ScriptDefault(),max_request_value: Uint128Default(),cap: Uint128Default(),period: Uint64Default(),period_start: Uint64Default(),minted: Uint128Default() }
+}
+    
+
+type MintQuota struct {
+    inner []byte
+}
+        
+
+func MintQuotaFromSliceUnchecked(slice []byte) *MintQuota {
+    return &MintQuota{inner: slice}
+}
+func (s *MintQuota) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func MintQuotaDefault() MintQuota {
+    return *MintQuotaFromSliceUnchecked([]byte{ 153,0,0,0,32,0,0,0,36,0,0,0,89,0,0,0,105,0,0,0,121,0,0,0,129,0,0,0,137,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func MintQuotaFromSlice(slice []byte, compatible bool) (*MintQuota, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "MintQuota", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "MintQuota", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "MintQuota", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "MintQuota", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "MintQuota", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 7 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 7 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = ScriptFromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint128FromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint128FromSlice(slice[offsets[3]:offsets[4]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[4]:offsets[5]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint64FromSlice(slice[offsets[5]:offsets[6]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint128FromSlice(slice[offsets[6]:offsets[7]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &MintQuota{inner: slice}, nil
+}
+            
+
+func (s *MintQuota) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *MintQuota) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *MintQuota) Len() uint {
+    return s.FieldCount()
+}
+func (s *MintQuota) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *MintQuota) CountExtraFields() uint {
+    return s.FieldCount() - 7
+}
+
+func (s *MintQuota) HasExtraFields() bool {
+    return 7 != s.FieldCount()
+}
+            
+
+func (s *MintQuota) TokenId() *Bytes {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *MintQuota) Merchant() *Script {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return ScriptFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *MintQuota) MaxRequestValue() *Uint128 {
+    start := unpackNumber(s.inner[12:])
+    end := unpackNumber(s.inner[16:])
+    return Uint128FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *MintQuota) Cap() *Uint128 {
+    start := unpackNumber(s.inner[16:])
+    end := unpackNumber(s.inner[20:])
+    return Uint128FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *MintQuota) Period() *Uint64 {
+    start := unpackNumber(s.inner[20:])
+    end := unpackNumber(s.inner[24:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *MintQuota) PeriodStart() *Uint64 {
+    start := unpackNumber(s.inner[24:])
+    end := unpackNumber(s.inner[28:])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *MintQuota) Minted() *Uint128 {
+    var ret *Uint128
+    start := unpackNumber(s.inner[28:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[32:])
+        ret = Uint128FromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = Uint128FromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *MintQuota) AsBuilder() MintQuotaBuilder {
+    ret := NewMintQuotaBuilder().TokenId(*s.TokenId()).Merchant(*s.Merchant()).MaxRequestValue(*s.MaxRequestValue()).Cap(*s.Cap()).Period(*s.Period()).PeriodStart(*s.PeriodStart()).Minted(*s.Minted())
+    return *ret
+}
+        
+
+type MintQuotasBuilder struct {
+    inner []MintQuota
+}
+    
+
+func (s *MintQuotasBuilder) Build() MintQuotas {
+    itemCount := len(s.inner)
+
+    b := new(bytes.Buffer)
+
+    // Empty dyn vector, just return size's bytes
+    if itemCount == 0 {
+        b.Write(packNumber(Number(HeaderSizeUint)))
+        return MintQuotas{inner: b.Bytes()}
+    }
+
+    // Calculate first offset then loop for rest items offsets
+    totalSize := HeaderSizeUint * uint32(itemCount+1)
+    offsets := make([]uint32, 0, itemCount)
+    offsets = append(offsets, totalSize)
+    for i := 1; i < itemCount; i++ {
+        totalSize += uint32(len(s.inner[i-1].AsSlice()))
+        offsets = append(offsets, offsets[i-1]+uint32(len(s.inner[i-1].AsSlice())))
+    }
+    totalSize += uint32(len(s.inner[itemCount-1].AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < itemCount; i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    for i := 0; i < itemCount; i++ {
+        b.Write(s.inner[i].AsSlice())
+    }
+
+    return MintQuotas{inner: b.Bytes()}
+}
+          
+
+func (s *MintQuotasBuilder) Set(v []MintQuota) *MintQuotasBuilder {
+    s.inner = v
+    return s
+}
+func (s *MintQuotasBuilder) Push(v MintQuota) *MintQuotasBuilder {
+    s.inner = append(s.inner, v)
+    return s
+}
+func (s *MintQuotasBuilder) Extend(iter []MintQuota) *MintQuotasBuilder {
+    for i:=0; i < len(iter); i++ {
+        s.inner = append(s.inner, iter[i])
+    }
+    return s
+}
+func (s *MintQuotasBuilder) Replace(index uint, v MintQuota) *MintQuota {
+    if uint(len(s.inner)) > index {
+        a := s.inner[index]
+        s.inner[index] = v
+        return &a
+    }
+    return nil
+}
+    
+
+func NewMintQuotasBuilder() *MintQuotasBuilder {
+	return &MintQuotasBuilder{ []MintQuota{} }
+}
+        
+
+type MintQuotas struct {
+    inner []byte
+}
+        
+
+func MintQuotasFromSliceUnchecked(slice []byte) *MintQuotas {
+    return &MintQuotas{inner: slice}
+}
+func (s *MintQuotas) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func MintQuotasDefault() MintQuotas {
+    return *MintQuotasFromSliceUnchecked([]byte{ 4,0,0,0 })
+}
+            
+
+func MintQuotasFromSlice(slice []byte, compatible bool) (*MintQuotas, error) {
+    sliceLen := len(slice)
+
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "MintQuotas", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "MintQuotas", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) == HeaderSizeUint {
+        return &MintQuotas{inner: slice}, nil
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "MintQuotas", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "MintQuotas", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "MintQuotas", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+    itemCount := uint32(offsetFirst)/HeaderSizeUint - 1
+
+    offsets := make([]uint32, itemCount)
+
+    for i := 0; i < int(itemCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            errMsg := strings.Join([]string{"OffsetsNotMatch", "MintQuotas"}, " ")
+            return nil, errors.New(errMsg)
+        }
+    }
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 {
+            start := offsets[i-1]
+            end := offsets[i]
+            _, err := MintQuotaFromSlice(slice[start:end], compatible)
+
+            if err != nil {
+                return nil, err
+            }
+        }
+    }
+
+    return &MintQuotas{inner: slice}, nil
+}
+            
+
+func (s *MintQuotas) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *MintQuotas) ItemCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *MintQuotas) Len() uint {
+    return s.ItemCount()
+}
+func (s *MintQuotas) IsEmpty() bool {
+    return s.Len() == 0
+}
+// if *MintQuota is nil, index is out of bounds
+func (s *MintQuotas) Get(index uint) *MintQuota {
+    var b *MintQuota
+    if index < s.Len() {
+        start_index := uint(HeaderSizeUint) * (1 + index)
+        start := unpackNumber(s.inner[start_index:])
+
+        if index == s.Len()-1 {
+            b = MintQuotaFromSliceUnchecked(s.inner[start:])
+        } else {
+            end_index := start_index + uint(HeaderSizeUint)
+            end := unpackNumber(s.inner[end_index:])
+            b = MintQuotaFromSliceUnchecked(s.inner[start:end])
+        }
+    }
+    return b
+}
+            
+
+func (s *MintQuotas) AsBuilder() MintQuotasBuilder {
+    size := s.ItemCount()
+    t := NewMintQuotasBuilder()
+    for i:=uint(0); i < size; i++ {
+        t.Push(*s.Get(i))
+    }
+    return *t
+}
+        
+
+type XudtSupplyBuilder struct {
+    token_id Bytes
+supply Uint128
+max_supply Uint128Opt
+}
+        
+
+func (s *XudtSupplyBuilder) Build() XudtSupply {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (3 + 1)
+    offsets := make([]uint32, 0, 3)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.token_id.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.supply.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.max_supply.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.token_id.AsSlice())
+b.Write(s.supply.AsSlice())
+b.Write(s.max_supply.AsSlice())
+    return XudtSupply{inner: b.Bytes()}
+}
+                
+
+func (s *XudtSupplyBuilder) TokenId(v Bytes) *XudtSupplyBuilder {
+    s.token_id = v
+    return s
+}
+            
+
+func (s *XudtSupplyBuilder) Supply(v Uint128) *XudtSupplyBuilder {
+    s.supply = v
+    return s
+}
+            
+
+func (s *XudtSupplyBuilder) MaxSupply(v Uint128Opt) *XudtSupplyBuilder {
+    s.max_supply = v
+    return s
+}
+            
+
+func NewXudtSupplyBuilder() *XudtSupplyBuilder {
+	return &XudtSupplyBuilder{ token_id: BytesDefault(),supply: Uint128Default(),max_supply: Uint128OptDefault() }
+}
+    
+
+type XudtSupply struct {
+    inner []byte
+}
+        
+
+func XudtSupplyFromSliceUnchecked(slice []byte) *XudtSupply {
+    return &XudtSupply{inner: slice}
+}
+func (s *XudtSupply) AsSlice() []byte {
+    return s.inner
+}
+            
+
+func XudtSupplyDefault() XudtSupply {
+    return *XudtSupplyFromSliceUnchecked([]byte{ 36,0,0,0,16,0,0,0,20,0,0,0,36,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+}
+            
+
+func XudtSupplyFromSlice(slice []byte, compatible bool) (*XudtSupply, error) {
+    sliceLen := len(slice)
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "XudtSupply", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "XudtSupply", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "XudtSupply", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "XudtSupply", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "XudtSupply", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
+    if fieldCount < 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    } else if !compatible && fieldCount > 3 {
+        return nil, errors.New("FieldCountNotMatch")
+    }
+
+    offsets := make([]uint32, fieldCount)
+
+    for i := 0; i < int(fieldCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            return nil, errors.New("OffsetsNotMatch")
+        }
+    }
+
+    var err error
+    
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint128FromSlice(slice[offsets[1]:offsets[2]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+_, err = Uint128OptFromSlice(slice[offsets[2]:offsets[3]], compatible)
+if err != nil {
+    return nil, err
+}
+                
+
+    return &XudtSupply{inner: slice}, nil
+}
+            
+
+func (s *XudtSupply) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *XudtSupply) FieldCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *XudtSupply) Len() uint {
+    return s.FieldCount()
+}
+func (s *XudtSupply) IsEmpty() bool {
+    return s.Len() == 0
+}
+func (s *XudtSupply) CountExtraFields() uint {
+    return s.FieldCount() - 3
+}
+
+func (s *XudtSupply) HasExtraFields() bool {
+    return 3 != s.FieldCount()
+}
+            
+
+func (s *XudtSupply) TokenId() *Bytes {
+    start := unpackNumber(s.inner[4:])
+    end := unpackNumber(s.inner[8:])
+    return BytesFromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *XudtSupply) Supply() *Uint128 {
+    start := unpackNumber(s.inner[8:])
+    end := unpackNumber(s.inner[12:])
+    return Uint128FromSliceUnchecked(s.inner[start:end])
+}
+               
+
+func (s *XudtSupply) MaxSupply() *Uint128Opt {
+    var ret *Uint128Opt
+    start := unpackNumber(s.inner[12:])
+    if s.HasExtraFields() {
+        end := unpackNumber(s.inner[16:])
+        ret = Uint128OptFromSliceUnchecked(s.inner[start:end])
+    } else {
+        ret = Uint128OptFromSliceUnchecked(s.inner[start:])
+    }
+    return ret
+}
+                        
+
+func (s *XudtSupply) AsBuilder() XudtSupplyBuilder {
+    ret := NewXudtSupplyBuilder().TokenId(*s.TokenId()).Supply(*s.Supply()).MaxSupply(*s.MaxSupply())
+    return *ret
 }
         
 
-func (s *TickBuilder) Build() Tick {
+type XudtSuppliesBuilder struct {
+    inner []XudtSupply
+}
+    
+
+func (s *XudtSuppliesBuilder) Build() XudtSupplies {
+    itemCount := len(s.inner)
+
     b := new(bytes.Buffer)
 
-    totalSize := HeaderSizeUint * (7 + 1)
-    offsets := make([]uint32, 0, 7)
+    // Empty dyn vector, just return size's bytes
+    if itemCount == 0 {
+        b.Write(packNumber(Number(HeaderSizeUint)))
+        return XudtSupplies{inner: b.Bytes()}
+    }
 
+    // Calculate first offset then loop for rest items offsets
+    totalSize := HeaderSizeUint * uint32(itemCount+1)
+    offsets := make([]uint32, 0, itemCount)
     offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.tick_type.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.token_id.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.value.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.merchant.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.coin_type.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.tx_hash.AsSlice()))
-offsets = append(offsets, totalSize)
-totalSize += uint32(len(s.receipt_addr.AsSlice()))
+    for i := 1; i < itemCount; i++ {
+        totalSize += uint32(len(s.inner[i-1].AsSlice()))
+        offsets = append(offsets, offsets[i-1]+uint32(len(s.inner[i-1].AsSlice())))
+    }
+    totalSize += uint32(len(s.inner[itemCount-1].AsSlice()))
 
     b.Write(packNumber(Number(totalSize)))
 
-    for i := 0; i < len(offsets); i++ {
+    for i := 0; i < itemCount; i++ {
         b.Write(packNumber(Number(offsets[i])))
     }
 
-    b.Write(s.tick_type.AsSlice())
-b.Write(s.token_id.AsSlice())
-b.Write(s.value.AsSlice())
-b.Write(s.merchant.AsSlice())
-b.Write(s.coin_type.AsSlice())
-b.Write(s.tx_hash.AsSlice())
-b.Write(s.receipt_addr.AsSlice())
-    return Tick{inner: b.Bytes()}
+    for i := 0; i < itemCount; i++ {
+        b.Write(s.inner[i].AsSlice())
+    }
+
+    return XudtSupplies{inner: b.Bytes()}
 }
-                
+          
 
-func (s *TickBuilder) TickType(v Byte) *TickBuilder {
-    s.tick_type = v
+func (s *XudtSuppliesBuilder) Set(v []XudtSupply) *XudtSuppliesBuilder {
+    s.inner = v
     return s
 }
+func (s *XudtSuppliesBuilder) Push(v XudtSupply) *XudtSuppliesBuilder {
+    s.inner = append(s.inner, v)
+    return s
+}
+func (s *XudtSuppliesBuilder) Extend(iter []XudtSupply) *XudtSuppliesBuilder {
+    for i:=0; i < len(iter); i++ {
+        s.inner = append(s.inner, iter[i])
+    }
+    return s
+}
+func (s *XudtSuppliesBuilder) Replace(index uint, v XudtSupply) *XudtSupply {
+    if uint(len(s.inner)) > index {
+        a := s.inner[index]
+        s.inner[index] = v
+        return &a
+    }
+    return nil
+}
+    
+
+func NewXudtSuppliesBuilder() *XudtSuppliesBuilder {
+	return &XudtSuppliesBuilder{ []XudtSupply{} }
+}
+        
+
+type XudtSupplies struct {
+    inner []byte
+}
+        
+
+func XudtSuppliesFromSliceUnchecked(slice []byte) *XudtSupplies {
+    return &XudtSupplies{inner: slice}
+}
+func (s *XudtSupplies) AsSlice() []byte {
+    return s.inner
+}
             
 
-func (s *TickBuilder) TokenId(v Bytes) *TickBuilder {
-    s.token_id = v
-    return s
+func XudtSuppliesDefault() XudtSupplies {
+    return *XudtSuppliesFromSliceUnchecked([]byte{ 4,0,0,0 })
 }
             
 
-func (s *TickBuilder) Value(v Uint128) *TickBuilder {
-    s.value = v
-    return s
+func XudtSuppliesFromSlice(slice []byte, compatible bool) (*XudtSupplies, error) {
+    sliceLen := len(slice)
+
+    if uint32(sliceLen) < HeaderSizeUint {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "XudtSupplies", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    totalSize := unpackNumber(slice)
+    if Number(sliceLen) != totalSize {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "XudtSupplies", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if uint32(sliceLen) == HeaderSizeUint {
+        return &XudtSupplies{inner: slice}, nil
+    }
+
+    if uint32(sliceLen) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "XudtSupplies", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    offsetFirst := unpackNumber(slice[HeaderSizeUint:])
+    if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "XudtSupplies", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        return nil, errors.New(errMsg)
+    }
+
+    if sliceLen < int(offsetFirst) {
+        errMsg := strings.Join([]string{"HeaderIsBroken", "XudtSupplies", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        return nil, errors.New(errMsg)
+    }
+    itemCount := uint32(offsetFirst)/HeaderSizeUint - 1
+
+    offsets := make([]uint32, itemCount)
+
+    for i := 0; i < int(itemCount); i++ {
+        offsets[i] = uint32(unpackNumber(slice[HeaderSizeUint:][int(HeaderSizeUint)*i:]))
+    }
+
+    offsets = append(offsets, uint32(totalSize))
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 && offsets[i-1] > offsets[i] {
+            errMsg := strings.Join([]string{"OffsetsNotMatch", "XudtSupplies"}, " ")
+            return nil, errors.New(errMsg)
+        }
+    }
+
+    for i := 0; i < len(offsets); i++ {
+        if i&1 != 0 {
+            start := offsets[i-1]
+            end := offsets[i]
+            _, err := XudtSupplyFromSlice(slice[start:end], compatible)
+
+            if err != nil {
+                return nil, err
+            }
+        }
+    }
+
+    return &XudtSupplies{inner: slice}, nil
 }
             
 
-func (s *TickBuilder) Merchant(v Script) *TickBuilder {
-    s.merchant = v
-    return s
+func (s *XudtSupplies) TotalSize() uint {
+    return uint(unpackNumber(s.inner))
+}
+func (s *XudtSupplies) ItemCount() uint {
+    var number uint = 0
+    if uint32(s.TotalSize()) == HeaderSizeUint {
+        return number
+    }
+    number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
+    return number
+}
+func (s *XudtSupplies) Len() uint {
+    return s.ItemCount()
+}
+func (s *XudtSupplies) IsEmpty() bool {
+    return s.Len() == 0
+}
+// if *XudtSupply is nil, index is out of bounds
+func (s *XudtSupplies) Get(index uint) *XudtSupply {
+    var b *XudtSupply
+    if index < s.Len() {
+        start_index := uint(HeaderSizeUint) * (1 + index)
+        start := unpackNumber(s.inner[start_index:])
+
+        if index == s.Len()-1 {
+            b = XudtSupplyFromSliceUnchecked(s.inner[start:])
+        } else {
+            end_index := start_index + uint(HeaderSizeUint)
+            end := unpackNumber(s.inner[end_index:])
+            b = XudtSupplyFromSliceUnchecked(s.inner[start:end])
+        }
+    }
+    return b
 }
             
 
-func (s *TickBuilder) CoinType(v Bytes) *TickBuilder {
+func (s *XudtSupplies) AsBuilder() XudtSuppliesBuilder {
+    size := s.ItemCount()
+    t := NewXudtSuppliesBuilder()
+    for i:=uint(0); i < size; i++ {
+        t.Push(*s.Get(i))
+    }
+    return *t
+}
+        
+
+type ReserveAttestationBuilder struct {
+    coin_type Bytes
+reserve Uint128
+block_height Uint64
+timestamp Uint64
+}
+        
+
+func (s *ReserveAttestationBuilder) Build() ReserveAttestation {
+    b := new(bytes.Buffer)
+
+    totalSize := HeaderSizeUint * (4 + 1)
+    offsets := make([]uint32, 0, 4)
+
+    offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.coin_type.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.reserve.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.block_height.AsSlice()))
+offsets = append(offsets, totalSize)
+totalSize += uint32(len(s.timestamp.AsSlice()))
+
+    b.Write(packNumber(Number(totalSize)))
+
+    for i := 0; i < len(offsets); i++ {
+        b.Write(packNumber(Number(offsets[i])))
+    }
+
+    b.Write(s.coin_type.AsSlice())
+b.Write(s.reserve.AsSlice())
+b.Write(s.block_height.AsSlice())
+b.Write(s.timestamp.AsSlice())
+    return ReserveAttestation{inner: b.Bytes()}
+}
+                
+
+func (s *ReserveAttestationBuilder) CoinType(v Bytes) *ReserveAttestationBuilder {
     s.coin_type = v
     return s
 }
             
 
-func (s *TickBuilder) TxHash(v Bytes) *TickBuilder {
-    s.tx_hash = v
+func (s *ReserveAttestationBuilder) Reserve(v Uint128) *ReserveAttestationBuilder {
+    s.reserve = v
     return s
 }
             
 
-func (s *TickBuilder) ReceiptAddr(v Bytes) *TickBuilder {
-    s.receipt_addr = v
+func (s *ReserveAttestationBuilder) BlockHeight(v Uint64) *ReserveAttestationBuilder {
+    s.block_height = v
     return s
 }
             
 
-func NewTickBuilder() *TickBuilder {
-	return &TickBuilder{ tick_type: ByteDefault(),token_id: BytesDefault(),value: Uint128Default(),merchant: ScriptDefault(),coin_type: BytesDefault(),tx_hash: BytesDefault(),receipt_addr: BytesDefault() }
+func (s *ReserveAttestationBuilder) Timestamp(v Uint64) *ReserveAttestationBuilder {
+    s.timestamp = v
+    return s
+}
+            
+
+func NewReserveAttestationBuilder() *ReserveAttestationBuilder {
+	return &ReserveAttestationBuilder{ coin_type: BytesDefault(),reserve: Uint128Default(),block_height: Uint64Default(),timestamp: Uint64Default() }
 }
     
 
-type Tick struct {
+type ReserveAttestation struct {
     inner []byte
 }
         
 
-func TickFromSliceUnchecked(slice []byte) *Tick {
-    return &Tick{inner: slice}
+func ReserveAttestationFromSliceUnchecked(slice []byte) *ReserveAttestation {
+    return &ReserveAttestation{inner: slice}
 }
-func (s *Tick) AsSlice() []byte {
+func (s *ReserveAttestation) AsSlice() []byte {
     return s.inner
 }
             
 
-func TickDefault() Tick {
-    return *TickFromSliceUnchecked([]byte{ 118,0,0,0,32,0,0,0,33,0,0,0,37,0,0,0,53,0,0,0,106,0,0,0,110,0,0,0,114,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,53,0,0,0,16,0,0,0,48,0,0,0,49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
+func ReserveAttestationDefault() ReserveAttestation {
+    return *ReserveAttestationFromSliceUnchecked([]byte{ 56,0,0,0,20,0,0,0,24,0,0,0,40,0,0,0,48,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0 })
 }
             
 
-func TickFromSlice(slice []byte, compatible bool) (*Tick, error) {
+func ReserveAttestationFromSlice(slice []byte, compatible bool) (*ReserveAttestation, error) {
     sliceLen := len(slice)
     if uint32(sliceLen) < HeaderSizeUint {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "Tick", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ReserveAttestation", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint))}, " ")
         return nil, errors.New(errMsg)
     }
 
     totalSize := unpackNumber(slice)
     if Number(sliceLen) != totalSize {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "Tick", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ReserveAttestation", strconv.Itoa(int(sliceLen)), "!=", strconv.Itoa(int(totalSize))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if uint32(sliceLen) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"TotalSizeNotMatch", "Tick", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"TotalSizeNotMatch", "ReserveAttestation", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     offsetFirst := unpackNumber(slice[HeaderSizeUint:])
     if uint32(offsetFirst)%HeaderSizeUint != 0 || uint32(offsetFirst) < HeaderSizeUint*2 {
-        errMsg := strings.Join([]string{"OffsetsNotMatch", "Tick", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
+        errMsg := strings.Join([]string{"OffsetsNotMatch", "ReserveAttestation", strconv.Itoa(int(offsetFirst%4)), "!= 0", strconv.Itoa(int(offsetFirst)), "<", strconv.Itoa(int(HeaderSizeUint*2))}, " ")
         return nil, errors.New(errMsg)
     }
 
     if sliceLen < int(offsetFirst) {
-        errMsg := strings.Join([]string{"HeaderIsBroken", "Tick", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
+        errMsg := strings.Join([]string{"HeaderIsBroken", "ReserveAttestation", strconv.Itoa(int(sliceLen)), "<", strconv.Itoa(int(offsetFirst))}, " ")
         return nil, errors.New(errMsg)
     }
 
     fieldCount := uint32(offsetFirst)/HeaderSizeUint - 1
-    if fieldCount < 7 {
+    if fieldCount < 4 {
         return nil, errors.New("FieldCountNotMatch")
-    } else if !compatible && fieldCount > 7 {
+    } else if !compatible && fieldCount > 4 {
         return nil, errors.New("FieldCountNotMatch")
     }
 
@@ -404,56 +2765,38 @@ func TickFromSlice(slice []byte, compatible bool) (*Tick, error) {
 
     var err error
     
-_, err = ByteFromSlice(slice[offsets[0]:offsets[1]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = BytesFromSlice(slice[offsets[1]:offsets[2]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = Uint128FromSlice(slice[offsets[2]:offsets[3]], compatible)
-if err != nil {
-    return nil, err
-}
-                
-
-_, err = ScriptFromSlice(slice[offsets[3]:offsets[4]], compatible)
+_, err = BytesFromSlice(slice[offsets[0]:offsets[1]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[4]:offsets[5]], compatible)
+_, err = Uint128FromSlice(slice[offsets[1]:offsets[2]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[5]:offsets[6]], compatible)
+_, err = Uint64FromSlice(slice[offsets[2]:offsets[3]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-_, err = BytesFromSlice(slice[offsets[6]:offsets[7]], compatible)
+_, err = Uint64FromSlice(slice[offsets[3]:offsets[4]], compatible)
 if err != nil {
     return nil, err
 }
                 
 
-    return &Tick{inner: slice}, nil
+    return &ReserveAttestation{inner: slice}, nil
 }
             
 
-func (s *Tick) TotalSize() uint {
+func (s *ReserveAttestation) TotalSize() uint {
     return uint(unpackNumber(s.inner))
 }
-func (s *Tick) FieldCount() uint {
+func (s *ReserveAttestation) FieldCount() uint {
     var number uint = 0
     if uint32(s.TotalSize()) == HeaderSizeUint {
         return number
@@ -461,78 +2804,57 @@ func (s *Tick) FieldCount() uint {
     number = uint(unpackNumber(s.inner[HeaderSizeUint:]))/4 - 1
     return number
 }
-func (s *Tick) Len() uint {
+func (s *ReserveAttestation) Len() uint {
     return s.FieldCount()
 }
-func (s *Tick) IsEmpty() bool {
+func (s *ReserveAttestation) IsEmpty() bool {
     return s.Len() == 0
 }
-func (s *Tick) CountExtraFields() uint {
-    return s.FieldCount() - 7
+func (s *ReserveAttestation) CountExtraFields() uint {
+    return s.FieldCount() - 4
 }
 
-func (s *Tick) HasExtraFields() bool {
-    return 7 != s.FieldCount()
+func (s *ReserveAttestation) HasExtraFields() bool {
+    return 4 != s.FieldCount()
 }
             
 
-func (s *Tick) TickType() *Byte {
+func (s *ReserveAttestation) CoinType() *Bytes {
     start := unpackNumber(s.inner[4:])
     end := unpackNumber(s.inner[8:])
-    return ByteFromSliceUnchecked(s.inner[start:end])
+    return BytesFromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *Tick) TokenId() *Bytes {
+func (s *ReserveAttestation) Reserve() *Uint128 {
     start := unpackNumber(s.inner[8:])
     end := unpackNumber(s.inner[12:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
+    return Uint128FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *Tick) Value() *Uint128 {
+func (s *ReserveAttestation) BlockHeight() *Uint64 {
     start := unpackNumber(s.inner[12:])
     end := unpackNumber(s.inner[16:])
-    return Uint128FromSliceUnchecked(s.inner[start:end])
+    return Uint64FromSliceUnchecked(s.inner[start:end])
 }
                
 
-func (s *Tick) Merchant() *Script {
+func (s *ReserveAttestation) Timestamp() *Uint64 {
+    var ret *Uint64
     start := unpackNumber(s.inner[16:])
-    end := unpackNumber(s.inner[20:])
-    return ScriptFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *Tick) CoinType() *Bytes {
-    start := unpackNumber(s.inner[20:])
-    end := unpackNumber(s.inner[24:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *Tick) TxHash() *Bytes {
-    start := unpackNumber(s.inner[24:])
-    end := unpackNumber(s.inner[28:])
-    return BytesFromSliceUnchecked(s.inner[start:end])
-}
-               
-
-func (s *Tick) ReceiptAddr() *Bytes {
-    var ret *Bytes
-    start := unpackNumber(s.inner[28:])
     if s.HasExtraFields() {
-        end := unpackNumber(s.inner[32:])
-        ret = BytesFromSliceUnchecked(s.inner[start:end])
+        end := unpackNumber(s.inner[20:])
+        ret = Uint64FromSliceUnchecked(s.inner[start:end])
     } else {
-        ret = BytesFromSliceUnchecked(s.inner[start:])
+        ret = Uint64FromSliceUnchecked(s.inner[start:])
     }
     return ret
 }
                         
 
-func (s *Tick) AsBuilder() TickBuilder {
-    ret := NewTickBuilder().TickType(*s.TickType()).TokenId(*s.TokenId()).Value(*s.Value()).Merchant(*s.Merchant()).CoinType(*s.CoinType()).TxHash(*s.TxHash()).ReceiptAddr(*s.ReceiptAddr())
+func (s *ReserveAttestation) AsBuilder() ReserveAttestationBuilder {
+    ret := NewReserveAttestationBuilder().CoinType(*s.CoinType()).Reserve(*s.Reserve()).BlockHeight(*s.BlockHeight()).Timestamp(*s.Timestamp())
     return *ret
 }
         
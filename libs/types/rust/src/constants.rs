@@ -27,6 +27,9 @@ pub const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
 
 pub const OMNI_FLAG_MULTISIG: u8 = 6;
 pub const OMNI_FLAG_NO_MODE: u8 = 0;
+/// The members of a multisig are blake160 hashes of pubkeys, and their count is encoded in a single byte.
+pub const MULTISIG_MEMBER_SIZE: usize = 20;
+pub const MULTISIG_MAX_MEMBERS: usize = 255;
 
 pub const TOKEN_ID_SIZE: usize = 32;
 pub const XUDT_OWNER_LOCK_HASH_SIZE: usize = 32;
@@ -97,6 +100,20 @@ pub enum Action {
     ConfirmBurn,
     #[strum(serialize = "reject_burn")]
     RejectBurn,
+    /// Withdraw a pending mint TickCell by its merchant at any time, whether or not it has a deadline.
+    #[strum(serialize = "cancel_mint")]
+    CancelMint,
+    /// Withdraw a pending burn TickCell by its merchant at any time, whether or not it has a deadline.
+    #[strum(serialize = "cancel_burn")]
+    CancelBurn,
+    #[strum(serialize = "confirm_mint_partial")]
+    ConfirmMintPartial,
+    #[strum(serialize = "force_close_tick")]
+    ForceCloseTick,
+    #[strum(serialize = "propose_custodians")]
+    ProposeCustodians,
+    #[strum(serialize = "cancel_custodian_proposal")]
+    CancelCustodianProposal,
     #[default]
     Others,
 }
@@ -118,6 +135,8 @@ pub enum GovernanceMemberRole {
     Custodian,
     #[strum(serialize = "merchant")]
     Merchant,
+    #[strum(serialize = "custodian_proposal")]
+    CustodianProposal,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, EnumString, Display, TryFromPrimitive)]
@@ -159,6 +178,42 @@ pub enum ConfigKey {
     XudtOwnerTypeArgs,
     #[strum(serialize = "xudt_info_cell_type_out_point")]
     XudtInfoCellTypeOutPoint,
+    #[strum(serialize = "deposit_registry_cell_type_args")]
+    DepositRegistryCellTypeArgs,
+    #[strum(serialize = "mint_quota_cell_type_args")]
+    MintQuotaCellTypeArgs,
+    #[strum(serialize = "fee_lock")]
+    FeeLock,
+    #[strum(serialize = "fee_rate")]
+    FeeRate,
+    #[strum(serialize = "fee_min")]
+    FeeMin,
+    #[strum(serialize = "fee_max")]
+    FeeMax,
+    #[strum(serialize = "tick_receipt_type")]
+    TickReceiptType,
+    #[strum(serialize = "request_mint_status")]
+    RequestMintStatus,
+    #[strum(serialize = "request_burn_status")]
+    RequestBurnStatus,
+    #[strum(serialize = "confirm_mint_status")]
+    ConfirmMintStatus,
+    #[strum(serialize = "confirm_burn_status")]
+    ConfirmBurnStatus,
+    #[strum(serialize = "governance_status")]
+    GovernanceStatus,
+    #[strum(serialize = "frozen_token_ids")]
+    FrozenTokenIds,
+    #[strum(serialize = "reserve_attestation_type")]
+    ReserveAttestationType,
+    #[strum(serialize = "reserve_attestation_max_age")]
+    ReserveAttestationMaxAge,
+    #[strum(serialize = "custodian_cosign_status")]
+    CustodianCosignStatus,
+    #[strum(serialize = "xudt_supply_cell_type_args")]
+    XudtSupplyCellTypeArgs,
+    #[strum(serialize = "custodian_proposal_delay")]
+    CustodianProposalDelay,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, EnumString, Display, TryFromPrimitive)]
@@ -170,6 +225,15 @@ pub enum TickType {
     Burn = 1,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, EnumString, Display, TryFromPrimitive)]
+#[repr(u8)]
+pub enum TickOutcome {
+    #[strum(serialize = "confirmed")]
+    Confirmed = 0,
+    #[strum(serialize = "rejected")]
+    Rejected = 1,
+}
+
 pub fn deploy_lock() -> &'static Script {
     static mut DEPLOY_LOCK: OnceCell<Script> = OnceCell::new();
 
@@ -5,6 +5,7 @@ extern crate alloc;
 pub mod constants;
 pub mod convert;
 pub mod data_parser;
+pub mod smt;
 pub mod util;
 
 mod schemas;
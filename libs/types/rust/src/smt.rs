@@ -0,0 +1,56 @@
+#[cfg(feature = "no_std")]
+use blake2b_ref::Blake2b;
+#[cfg(not(feature = "no_std"))]
+use blake2b_rs::Blake2b;
+use sparse_merkle_tree::traits::Hasher;
+pub use sparse_merkle_tree::{CompiledMerkleProof, H256};
+
+use super::util::new_blake2b;
+
+/// The value of an existing key in SMT, it is the same as the SMT_VALUE_EXISTING of xUDT RCE.
+pub const SMT_VALUE_EXISTING: [u8; 32] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+pub const SMT_VALUE_NOT_EXISTING: [u8; 32] = [0u8; 32];
+
+/// The packed_values of SmtUpdateItem which means the key is inserted, high 4 bits are the old value and low 4 bits are
+/// the new value.
+pub const SMT_UPDATE_INSERT: u8 = 0x01;
+/// The packed_values of SmtUpdateItem which means the key is removed.
+pub const SMT_UPDATE_REMOVE: u8 = 0x10;
+
+pub struct Blake2bHasher(Blake2b);
+
+impl Default for Blake2bHasher {
+    fn default() -> Self {
+        Blake2bHasher(new_blake2b())
+    }
+}
+
+impl Hasher for Blake2bHasher {
+    fn write_h256(&mut self, h: &H256) {
+        self.0.update(h.as_slice());
+    }
+
+    fn write_byte(&mut self, b: u8) {
+        self.0.update(&[b][..]);
+    }
+
+    fn finish(self) -> H256 {
+        let mut hash = [0u8; 32];
+        self.0.finalize(&mut hash);
+        hash.into()
+    }
+}
+
+/// Build the key of a deposit on the foreign chain in the DepositRegistryCell, the coin_type is length prefixed so
+/// that different combinations of coin_type and tx_hash can never produce the same preimage.
+pub fn deposit_key(coin_type: &[u8], tx_hash: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    let mut blake2b = new_blake2b();
+    blake2b.update(&(coin_type.len() as u32).to_le_bytes());
+    blake2b.update(coin_type);
+    blake2b.update(tx_hash);
+    blake2b.finalize(&mut key);
+    key
+}
@@ -188,5 +188,5 @@ fn challenge_config_deploy_without_owner_lock() {
     });
 
     let mut template_parser = util::init_template_parser();
-    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::OwnerLockIsRequired as i8))
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::OwnerLockIsRequiredInInputs as i8))
 }
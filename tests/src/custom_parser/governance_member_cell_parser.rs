@@ -81,79 +81,91 @@ impl GovernanceMemberCell {
         } else {
             let version_bytes = parse_version("Field `cell.data.version`", &cell["tmp_data"]["version"])?;
 
-            let mut builder = cc_types::GovernanceMembers::new_builder();
-
-            // parse parent_id
-            let parent_id = util::parse_json_hex(
-                "Field `cell.tmp_data.parent_id`",
-                &cell["tmp_data"]["parent_id"],
-                Some(vec![]),
-            );
-            builder = builder.parent_id(cc_types::Bytes::from_slice(parent_id.pack().as_slice()).unwrap());
-
-            let members = util::parse_json_array("Field `cell.tmp_data.members`", &cell["tmp_data"]["members"]);
-            let mut members_mol = cc_types::BytesVec::new_builder();
-            let mut members_bytes = vec![];
-            for (i, member) in members.iter().enumerate() {
-                let pubkey_hash =
-                    util::parse_json_hex(format!("Field `cell.tmp_data.members[{}]`", i), &member, Some(vec![]));
-                let pubkey_hash_bytes = cc_types::Bytes::from_slice(pubkey_hash.pack().as_slice()).unwrap();
-
-                members_mol = members_mol.push(pubkey_hash_bytes);
-                members_bytes.push(pubkey_hash);
-            }
-            builder = builder.members(members_mol.build());
-
-            let multisig_args = if cell["tmp_data"]["multisig_args"].is_null() {
-                cc_types::Bytes::default()
+            if version_bytes.as_ref() == [1u8] {
+                data = Self::parse_data_v1(&cell["tmp_data"], version_bytes);
             } else {
-                let require_first_n = util::parse_json_u8(
-                    "Field `cell.tmp_data.multisig_args.require_first_n`",
-                    &cell["tmp_data"]["multisig_args"]["require_first_n"],
-                    None,
-                );
-                let threshold = util::parse_json_u8(
-                    "Field `cell.tmp_data.multisig_args.threshold`",
-                    &cell["tmp_data"]["multisig_args"]["threshold"],
-                    None,
+                let mut builder = cc_types::GovernanceMembers::new_builder();
+
+                // parse parent_id
+                let parent_id = util::parse_json_hex(
+                    "Field `cell.tmp_data.parent_id`",
+                    &cell["tmp_data"]["parent_id"],
+                    Some(vec![]),
                 );
-                cc_types::Bytes::from(vec![0, require_first_n, threshold])
-            };
-            builder = builder.multisig_args(multisig_args);
+                builder = builder.parent_id(cc_types::Bytes::from_slice(parent_id.pack().as_slice()).unwrap());
 
-            // parse lock_args, if not exist, calculate from members
-            let lock_args = if cell["tmp_data"]["multisig_args"].is_null() && cell["tmp_data"]["lock_args"].is_null() {
-                cc_types::Bytes::default()
-            } else {
-                let multisig_args = match cell["tmp_data"]["lock_args"].as_str() {
-                    // If the lock_args is a string, parse it as hex
-                    Some(_) => {
-                        util::parse_json_hex("Field `cell.tmp_data.lock_args`", &cell["tmp_data"]["lock_args"], None)
-                    }
-                    // Otherwise, build it from multisig_args
-                    None => {
-                        let require_first_n = util::parse_json_u8(
-                            "Field `cell.tmp_data.multisig_args.require_first_n`",
-                            &cell["tmp_data"]["multisig_args"]["require_first_n"],
-                            None,
-                        );
-                        let threshold = util::parse_json_u8(
-                            "Field `cell.tmp_data.multisig_args.threshold`",
-                            &cell["tmp_data"]["multisig_args"]["threshold"],
-                            None,
-                        );
-
-                        util::build_omni_lock_multisig_args(require_first_n, threshold, members_bytes)
-                    }
+                let members = util::parse_json_array("Field `cell.tmp_data.members`", &cell["tmp_data"]["members"]);
+                let mut members_mol = cc_types::BytesVec::new_builder();
+                let mut members_bytes = vec![];
+                for (i, member) in members.iter().enumerate() {
+                    let pubkey_hash =
+                        util::parse_json_hex(format!("Field `cell.tmp_data.members[{}]`", i), &member, Some(vec![]));
+                    let pubkey_hash_bytes = cc_types::Bytes::from_slice(pubkey_hash.pack().as_slice()).unwrap();
+
+                    members_mol = members_mol.push(pubkey_hash_bytes);
+                    members_bytes.push(pubkey_hash);
+                }
+                builder = builder.members(members_mol.build());
+
+                let multisig_args = if cell["tmp_data"]["multisig_args"].is_null() {
+                    cc_types::Bytes::default()
+                } else {
+                    let require_first_n = util::parse_json_u8(
+                        "Field `cell.tmp_data.multisig_args.require_first_n`",
+                        &cell["tmp_data"]["multisig_args"]["require_first_n"],
+                        None,
+                    );
+                    let threshold = util::parse_json_u8(
+                        "Field `cell.tmp_data.multisig_args.threshold`",
+                        &cell["tmp_data"]["multisig_args"]["threshold"],
+                        None,
+                    );
+                    cc_types::Bytes::from(vec![0, require_first_n, threshold])
                 };
+                builder = builder.multisig_args(multisig_args);
 
-                cc_types::Bytes::from_slice(multisig_args.pack().as_slice()).unwrap()
-            };
-            builder = builder.lock_args(lock_args);
+                // parse lock_args, if not exist, calculate from members
+                let lock_args =
+                    if cell["tmp_data"]["multisig_args"].is_null() && cell["tmp_data"]["lock_args"].is_null() {
+                        cc_types::Bytes::default()
+                    } else {
+                        let multisig_args = match cell["tmp_data"]["lock_args"].as_str() {
+                            // If the lock_args is a string, parse it as hex
+                            Some(_) => util::parse_json_hex(
+                                "Field `cell.tmp_data.lock_args`",
+                                &cell["tmp_data"]["lock_args"],
+                                None,
+                            ),
+                            // Otherwise, build it from multisig_args
+                            None => {
+                                let require_first_n = util::parse_json_u8(
+                                    "Field `cell.tmp_data.multisig_args.require_first_n`",
+                                    &cell["tmp_data"]["multisig_args"]["require_first_n"],
+                                    None,
+                                );
+                                let threshold = util::parse_json_u8(
+                                    "Field `cell.tmp_data.multisig_args.threshold`",
+                                    &cell["tmp_data"]["multisig_args"]["threshold"],
+                                    None,
+                                );
+
+                                util::build_omni_lock_multisig_args(require_first_n, threshold, members_bytes)
+                            }
+                        };
 
-            let mol_bytes = bytes::Bytes::from(builder.build().as_slice().to_vec());
+                        cc_types::Bytes::from_slice(multisig_args.pack().as_slice()).unwrap()
+                    };
+                builder = builder.lock_args(lock_args);
+
+                // The data of custodian proposals wraps the members with the custodian_id and activation_epoch
+                let mol_bytes = if cell["tmp_data"]["custodian_id"].is_null() {
+                    bytes::Bytes::from(builder.build().as_slice().to_vec())
+                } else {
+                    Self::parse_proposal(&cell["tmp_data"], builder.build())
+                };
 
-            data = [version_bytes, mol_bytes].concat().into();
+                data = [version_bytes, mol_bytes].concat().into();
+            }
         }
 
         let cell_output = CellOutput::new_builder()
@@ -166,6 +178,43 @@ impl GovernanceMemberCell {
     }
 }
 
+impl GovernanceMemberCell {
+    /// The version 1 data only contains the parent_id and the SMT root of the merchant lock hashes.
+    fn parse_data_v1(tmp_data: &Value, version_bytes: bytes::Bytes) -> bytes::Bytes {
+        let parent_id = util::parse_json_hex("Field `cell.tmp_data.parent_id`", &tmp_data["parent_id"], Some(vec![]));
+        let members_root = util::parse_json_hex("Field `cell.tmp_data.members_root`", &tmp_data["members_root"], None);
+
+        let members = cc_types::GovernanceMembersV1::new_builder()
+            .parent_id(cc_types::Bytes::from_slice(parent_id.pack().as_slice()).unwrap())
+            .members_root(
+                cc_types::Byte32::from_slice(&members_root)
+                    .expect("Field `cell.tmp_data.members_root` should be 32 bytes."),
+            )
+            .build();
+
+        let mol_bytes = bytes::Bytes::from(members.as_slice().to_vec());
+
+        [version_bytes, mol_bytes].concat().into()
+    }
+
+    fn parse_proposal(tmp_data: &Value, members: cc_types::GovernanceMembers) -> bytes::Bytes {
+        let custodian_id = util::parse_json_hex("Field `cell.tmp_data.custodian_id`", &tmp_data["custodian_id"], None);
+        let activation_epoch = util::parse_json_u64(
+            "Field `cell.tmp_data.activation_epoch`",
+            &tmp_data["activation_epoch"],
+            None,
+        );
+
+        let proposal = cc_types::CustodianProposal::new_builder()
+            .custodian_id(cc_types::Bytes::from_slice(custodian_id.pack().as_slice()).unwrap())
+            .members(members)
+            .activation_epoch(cc_types::Uint64::from(activation_epoch))
+            .build();
+
+        bytes::Bytes::from(proposal.as_slice().to_vec())
+    }
+}
+
 impl CellParser for GovernanceMemberCell {
     fn get_keyword(&self) -> String {
         self.keyword.clone()
@@ -0,0 +1,179 @@
+use std::cell::RefCell;
+use std::error::Error as StdError;
+use std::rc::Rc;
+
+use ckb_testtool::ckb_types::bytes;
+use ckb_testtool::ckb_types::packed::{Byte, CellOutput, ScriptOpt};
+use ckb_testtool::ckb_types::prelude::{Builder, Entity, Pack};
+use serde_json::Value;
+use types::packed::{MintQuota, MintQuotas, Uint128, Uint64};
+
+use super::super::template_parser::constants::Source;
+use super::super::template_parser::{util, CellParser, ScriptParser};
+use super::common::parse_version;
+use crate::template_parser::VarParser;
+
+pub struct MintQuotaCell {
+    pub keyword: String,
+}
+
+impl MintQuotaCell {
+    pub fn new() -> Self {
+        Self {
+            keyword: String::from("MintQuotaCell"),
+        }
+    }
+
+    fn parse_quota(
+        script_parser: &ScriptParser,
+        quota: &Value,
+        i: usize,
+        source: Source,
+    ) -> Result<MintQuota, Box<dyn StdError>> {
+        let field_name = |name: &str| format!("Field `cell.tmp_data.quotas[{}].{}`", i, name);
+
+        let token_id = match quota["token_id"].as_str() {
+            Some(_) => util::parse_json_hex(field_name("token_id"), &quota["token_id"], None),
+            None => {
+                let owner_script = script_parser
+                    .parse(quota["token_id"].clone(), source)?
+                    .expect("The token_id should be a valid Script structure if it is not a hex string.");
+                owner_script.calc_script_hash().as_slice().to_vec()
+            }
+        };
+        let merchant = script_parser
+            .parse(quota["merchant"].clone(), source)
+            .map_err(|err| format!("{} parse failed: {}", field_name("merchant"), err))?
+            .expect("Field `cell.tmp_data.quotas[].merchant` is required");
+        let merchant = types::packed::Script::from_slice(merchant.as_slice()).unwrap();
+
+        let parse_u128 = |name: &str| {
+            let value = util::parse_json_u64(&field_name(name), &quota[name], Some(0)) as u128;
+            Uint128::from_slice(value.to_le_bytes().as_slice()).unwrap()
+        };
+        let parse_u64 = |name: &str| {
+            let value = util::parse_json_u64(&field_name(name), &quota[name], Some(0));
+            Uint64::from_slice(value.to_le_bytes().as_slice()).unwrap()
+        };
+
+        Ok(MintQuota::new_builder()
+            .token_id(token_id.into())
+            .merchant(merchant)
+            .max_request_value(parse_u128("max_request_value"))
+            .cap(parse_u128("cap"))
+            .period(parse_u64("period"))
+            .period_start(parse_u64("period_start"))
+            .minted(parse_u128("minted"))
+            .build())
+    }
+
+    fn parse_cell(
+        _var_parser: Rc<RefCell<VarParser>>,
+        script_parser: &ScriptParser,
+        cell: Value,
+        source: Source,
+    ) -> Result<(CellOutput, bytes::Bytes), Box<dyn StdError>> {
+        // parse capacity of cell
+        let capacity = util::parse_json_u64("cell.capacity", &cell["capacity"], Some(0));
+
+        // parse cell.lock
+        let lock_script = script_parser
+            .parse(cell["lock"].clone(), source)
+            .map_err(|err| format!("Field `cell.lock` parse failed: {}", err))?;
+
+        // parse cell.type
+        let type_script = script_parser
+            .parse(cell["type"].clone(), source)
+            .map_err(|err| format!("Field `cell.type` parse failed: {}", err))?;
+
+        // parse cell.data
+        let data = if cell["tmp_data"].is_null() {
+            bytes::Bytes::new()
+        } else {
+            let version_bytes = parse_version("Field `cell.data.version`", &cell["tmp_data"]["version"])?;
+
+            let quotas = util::parse_json_array("Field `cell.tmp_data.quotas`", &cell["tmp_data"]["quotas"]);
+            let mut builder = MintQuotas::new_builder();
+            for (i, quota) in quotas.iter().enumerate() {
+                builder = builder.push(Self::parse_quota(script_parser, quota, i, source)?);
+            }
+            let mol_bytes = bytes::Bytes::from(builder.build().as_slice().to_vec());
+
+            [version_bytes, mol_bytes].concat().into()
+        };
+
+        let cell_output = CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(lock_script.expect("lock script is required"))
+            .type_(ScriptOpt::new_builder().set(type_script).build())
+            .build();
+
+        Ok((cell_output, data))
+    }
+}
+
+impl CellParser for MintQuotaCell {
+    fn get_keyword(&self) -> String {
+        self.keyword.clone()
+    }
+
+    fn parse_cell_deps(
+        &self,
+        var_parser: Rc<RefCell<VarParser>>,
+        script_parser: &ScriptParser,
+        data: Value,
+        index: usize,
+    ) -> Result<(Byte, CellOutput, bytes::Bytes), Box<dyn StdError>> {
+        // parse cell_deps[].out_point as a mock cell
+        let (cell_output, cell_data) = Self::parse_cell(
+            var_parser.clone(),
+            script_parser,
+            data["out_point"].clone(),
+            Source::CellDep,
+        )
+        .map_err(|err| format!("Field `cell_deps[{}].out_point` parse failed: {}", index, err))?;
+
+        // parse cell_deps[].dep_type
+        let dep_type = util::parse_json_u8(&format!("cell_deps[{}].dep_type", index), &data["dep_type"], Some(0));
+        let dep_type = Byte::new(dep_type);
+
+        Ok((dep_type, cell_output, cell_data))
+    }
+
+    fn parse_inputs(
+        &self,
+        var_parser: Rc<RefCell<VarParser>>,
+        script_parser: &ScriptParser,
+        data: Value,
+        index: usize,
+    ) -> Result<(u64, CellOutput, bytes::Bytes), Box<dyn StdError>> {
+        // parse inputs[].previous_output as a mock cell
+        let (cell_output, cell_data) = Self::parse_cell(
+            var_parser.clone(),
+            script_parser,
+            data["previous_output"].clone(),
+            Source::Input,
+        )
+        .map_err(|err| format!("Field `inputs[{}].previous_output` parse failed: {}", index, err))?;
+
+        // parse inputs[].since
+        let since = util::parse_json_u64(&format!("inputs[{}].since", index), &data["since"], Some(0));
+
+        Ok((since, cell_output, cell_data))
+    }
+
+    fn parse_outputs(
+        &self,
+        var_parser: Rc<RefCell<VarParser>>,
+        script_parser: &ScriptParser,
+        data: Value,
+        index: usize,
+    ) -> Result<(CellOutput, bytes::Bytes), Box<dyn StdError>> {
+        // parse outputs[] as a mock cell
+        let (cell_output, cell_data) =
+            Self::parse_cell(var_parser.clone(), script_parser, data.clone(), Source::Output)
+                .map_err(|err| format!("Field `outputs[{}]` parse failed: {}", index, err))?;
+
+        Ok((cell_output, cell_data))
+    }
+}
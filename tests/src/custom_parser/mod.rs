@@ -1,10 +1,18 @@
 mod common;
 mod config_cell_parser;
 mod governance_member_cell_parser;
+mod mint_quota_cell_parser;
+mod reserve_attestation_cell_parser;
 mod tick_cell_parser;
+mod tick_receipt_cell_parser;
 mod xudt_cell_parser;
+mod xudt_supply_cell_parser;
 
 pub use config_cell_parser::ConfigCell;
 pub use governance_member_cell_parser::GovernanceMemberCell;
+pub use mint_quota_cell_parser::MintQuotaCell;
+pub use reserve_attestation_cell_parser::ReserveAttestationCell;
 pub use tick_cell_parser::TickCell;
+pub use tick_receipt_cell_parser::TickReceiptCell;
 pub use xudt_cell_parser::XudtCell;
+pub use xudt_supply_cell_parser::XudtSupplyCell;
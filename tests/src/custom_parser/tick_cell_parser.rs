@@ -6,7 +6,7 @@ use ckb_testtool::ckb_types::bytes;
 use ckb_testtool::ckb_types::packed::{Byte, CellOutput, ScriptOpt};
 use ckb_testtool::ckb_types::prelude::{Builder, Entity, Pack};
 use serde_json::Value;
-use types::packed::{Tick, Uint128};
+use types::packed::{Tick, TickV1, Uint128, Uint64, Uint64Opt};
 
 use super::super::template_parser::constants::Source;
 use super::super::template_parser::{util, CellParser, ScriptParser};
@@ -42,21 +42,34 @@ impl TickCell {
             .parse(cell["type"].clone(), source)
             .map_err(|err| format!("Field `cell.type` parse failed: {}", err.to_string()))?;
 
+        let data = Self::parse_tick_data(script_parser, &cell["tmp_data"], source)?;
+
+        let cell_output = CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(lock_script.expect("lock script is required"))
+            .type_(ScriptOpt::new_builder().set(type_script).build())
+            .build();
+
+        Ok((cell_output, data.into()))
+    }
+
+    /// Build the data of TickCell from the tmp_data, it is also used by the TickReceipt cell to record the TickCell.
+    pub fn parse_tick_data(
+        script_parser: &ScriptParser,
+        tmp_data: &Value,
+        source: Source,
+    ) -> Result<Vec<u8>, Box<dyn StdError>> {
         let mut args = vec![];
-        let version = util::parse_json_u8(
-            "Field `cell.type.tmp_data.version`",
-            &cell["tmp_data"]["version"],
-            Some(0),
-        );
+        let version = util::parse_json_u8("Field `cell.type.tmp_data.version`", &tmp_data["version"], Some(0));
         args.extend(version.to_le_bytes().to_vec());
 
         // parse cell.data Tick
-        let tick_data = if cell["tmp_data"].is_null() {
+        let tick_data = if tmp_data.is_null() {
             Tick::default().as_bytes()
         } else {
             let tick_type = util::parse_json_str(
                 "Field `cell.tmp_data.Tick.tick_type`",
-                &cell["tmp_data"]["Tick"]["tick_type"],
+                &tmp_data["Tick"]["tick_type"],
                 "mint",
             );
             let tick = if tick_type == "mint" {
@@ -65,15 +78,15 @@ impl TickCell {
                 Byte::new(1)
             };
 
-            let token_id = match cell["tmp_data"]["Tick"]["token_id"].as_str() {
+            let token_id = match tmp_data["Tick"]["token_id"].as_str() {
                 Some(_) => util::parse_json_hex(
                     "Field `cell.tmp_data.Tick.token_id`",
-                    &cell["tmp_data"]["Tick"]["token_id"],
+                    &tmp_data["Tick"]["token_id"],
                     None,
                 ),
                 None => {
                     let owner_script = script_parser.parse(
-                        cell["tmp_data"]["Tick"]["token_id"].clone(),
+                        tmp_data["Tick"]["token_id"].clone(),
                         source,
                     )?
                     .expect("The cell.type.args.owner_script_hash should be a valid Script structure if it is not a hex string.");
@@ -82,63 +95,85 @@ impl TickCell {
                     owner_script_hash.as_slice().to_vec()
                 }
             };
-            let value = util::parse_json_u64(
-                "Field `cell.tmp_data.Tick.value`",
-                &cell["tmp_data"]["Tick"]["value"],
-                None,
-            ) as u128;
+            let value = util::parse_json_u128("Field `cell.tmp_data.Tick.value`", &tmp_data["Tick"]["value"], None);
             let merchant = script_parser
-                .parse(cell["tmp_data"]["Tick"]["merchant"].clone(), source)
+                .parse(tmp_data["Tick"]["merchant"].clone(), source)
                 .map_err(|err| format!("Field `cell.tmp_data.Tick.merchant` parse failed: {}", err.to_string()))?
                 .expect("Field `cell.tmp_data.Tick.merchant` is required");
             let merchant = types::packed::Script::from_slice(merchant.as_slice()).unwrap();
 
-            let coin_type = if cell["tmp_data"]["Tick"]["coin_type"].is_null() {
+            let coin_type = if tmp_data["Tick"]["coin_type"].is_null() {
                 Vec::<u8>::new()
             } else {
                 util::parse_json_hex(
                     "Field `cell.tmp_data.Tick.coin_type`",
-                    &cell["tmp_data"]["Tick"]["coin_type"],
+                    &tmp_data["Tick"]["coin_type"],
                     None,
                 )
             };
-            let tx_hash = if cell["tmp_data"]["Tick"]["tx_hash"].is_null() {
+            let tx_hash = if tmp_data["Tick"]["tx_hash"].is_null() {
                 Vec::<u8>::new()
             } else {
-                util::parse_json_hex(
-                    "Field `cell.tmp_data.Tick.tx_hash`",
-                    &cell["tmp_data"]["Tick"]["tx_hash"],
-                    None,
-                )
+                util::parse_json_hex("Field `cell.tmp_data.Tick.tx_hash`", &tmp_data["Tick"]["tx_hash"], None)
             };
 
             let receipt_address = util::parse_json_str(
                 "Field `cell.tmp_data.Tick.receipt_addr`",
-                &cell["tmp_data"]["Tick"]["receipt_addr"],
+                &tmp_data["Tick"]["receipt_addr"],
                 "",
             );
 
-            let tick = Tick::new_builder()
-                .tick_type(tick)
-                .token_id(token_id.into())
-                .value(Uint128::from_slice(value.to_le_bytes().as_slice()).unwrap())
-                .merchant(merchant)
-                .coin_type(coin_type.into())
-                .tx_hash(tx_hash.into())
-                .receipt_addr(receipt_address.as_bytes().into())
-                .build();
-            tick.as_bytes()
+            if version == 0 {
+                let tick = Tick::new_builder()
+                    .tick_type(tick)
+                    .token_id(token_id.into())
+                    .value(Uint128::from_slice(value.to_le_bytes().as_slice()).unwrap())
+                    .merchant(merchant)
+                    .coin_type(coin_type.into())
+                    .tx_hash(tx_hash.into())
+                    .receipt_addr(receipt_address.as_bytes().into())
+                    .build();
+                tick.as_bytes()
+            } else {
+                let deadline = if tmp_data["Tick"]["deadline"].is_null() {
+                    None
+                } else {
+                    let deadline = util::parse_json_u64(
+                        "Field `cell.tmp_data.Tick.deadline`",
+                        &tmp_data["Tick"]["deadline"],
+                        None,
+                    );
+                    Some(Uint64::from_slice(deadline.to_le_bytes().as_slice()).unwrap())
+                };
+
+                // The escrowed flag can also be a number, so the invalid flags can be tested.
+                let escrowed = match tmp_data["Tick"]["escrowed"].as_bool() {
+                    Some(escrowed) => escrowed as u8,
+                    None => tmp_data["Tick"]["escrowed"].as_u64().unwrap_or(0) as u8,
+                };
+                let nonce =
+                    util::parse_json_u64("Field `cell.tmp_data.Tick.nonce`", &tmp_data["Tick"]["nonce"], Some(0));
+                let memo = util::parse_json_str("Field `cell.tmp_data.Tick.memo`", &tmp_data["Tick"]["memo"], "");
+
+                let tick = TickV1::new_builder()
+                    .tick_type(tick)
+                    .token_id(token_id.into())
+                    .value(Uint128::from_slice(value.to_le_bytes().as_slice()).unwrap())
+                    .merchant(merchant)
+                    .coin_type(coin_type.into())
+                    .tx_hash(tx_hash.into())
+                    .receipt_addr(receipt_address.as_bytes().into())
+                    .nonce(Uint64::from_slice(nonce.to_le_bytes().as_slice()).unwrap())
+                    .memo(memo.as_bytes().into())
+                    .deadline(Uint64Opt::new_builder().set(deadline).build())
+                    .escrowed(Byte::new(escrowed))
+                    .build();
+                tick.as_bytes()
+            }
         };
         args.extend(tick_data);
 
-        let cell_output = CellOutput::new_builder()
-            .capacity(capacity.pack())
-            .lock(lock_script.expect("lock script is required"))
-            .type_(ScriptOpt::new_builder().set(type_script).build())
-            .build();
-
-        let data = args.into();
-        Ok((cell_output, data))
+        Ok(args)
     }
 }
 
@@ -0,0 +1,175 @@
+use std::cell::RefCell;
+use std::error::Error as StdError;
+use std::rc::Rc;
+
+use ckb_testtool::ckb_types::bytes;
+use ckb_testtool::ckb_types::packed::{Byte, CellOutput, ScriptOpt};
+use ckb_testtool::ckb_types::prelude::{Builder, Entity, Pack};
+use serde_json::Value;
+use types::constants::TickOutcome;
+use types::packed::{Byte32, TickReceipt};
+
+use super::super::template_parser::constants::Source;
+use super::super::template_parser::{util, CellParser, ScriptParser};
+use super::common::parse_version;
+use super::TickCell;
+use crate::template_parser::VarParser;
+
+pub struct TickReceiptCell {
+    pub keyword: String,
+}
+
+impl TickReceiptCell {
+    pub fn new() -> Self {
+        Self {
+            keyword: String::from("TickReceiptCell"),
+        }
+    }
+
+    fn parse_receipt(
+        script_parser: &ScriptParser,
+        receipt: &Value,
+        source: Source,
+    ) -> Result<TickReceipt, Box<dyn StdError>> {
+        // The tick is in the same format as the tmp_data of TickCell.
+        let tick = TickCell::parse_tick_data(script_parser, &receipt["tick"], source)
+            .map_err(|err| format!("Field `cell.tmp_data.tick` parse failed: {}", err))?;
+
+        let outcome = util::parse_json_str("Field `cell.tmp_data.outcome`", &receipt["outcome"], "confirmed");
+        let outcome = outcome
+            .parse::<TickOutcome>()
+            .map_err(|_| "Field `cell.tmp_data.outcome` should be confirmed or rejected.")?;
+
+        let custodian_lock_hash = match receipt["custodian_lock"].as_str() {
+            Some(_) => util::parse_json_hex("Field `cell.tmp_data.custodian_lock`", &receipt["custodian_lock"], None),
+            None => {
+                let custodian_lock = script_parser
+                    .parse(receipt["custodian_lock"].clone(), source)?
+                    .expect("The custodian_lock should be a valid Script structure if it is not a hex string.");
+                custodian_lock.calc_script_hash().as_slice().to_vec()
+            }
+        };
+
+        let settlement_tx_hash = if receipt["settlement_tx_hash"].is_null() {
+            Vec::new()
+        } else {
+            util::parse_json_hex(
+                "Field `cell.tmp_data.settlement_tx_hash`",
+                &receipt["settlement_tx_hash"],
+                None,
+            )
+        };
+
+        Ok(TickReceipt::new_builder()
+            .tick(tick.into())
+            .outcome(Byte::new(outcome as u8))
+            .custodian_lock_hash(Byte32::from_slice(&custodian_lock_hash)?)
+            .settlement_tx_hash(settlement_tx_hash.into())
+            .build())
+    }
+
+    fn parse_cell(
+        _var_parser: Rc<RefCell<VarParser>>,
+        script_parser: &ScriptParser,
+        cell: Value,
+        source: Source,
+    ) -> Result<(CellOutput, bytes::Bytes), Box<dyn StdError>> {
+        // parse capacity of cell
+        let capacity = util::parse_json_u64("cell.capacity", &cell["capacity"], Some(0));
+
+        // parse cell.lock
+        let lock_script = script_parser
+            .parse(cell["lock"].clone(), source)
+            .map_err(|err| format!("Field `cell.lock` parse failed: {}", err))?;
+
+        // parse cell.type
+        let type_script = script_parser
+            .parse(cell["type"].clone(), source)
+            .map_err(|err| format!("Field `cell.type` parse failed: {}", err))?;
+
+        // parse cell.data
+        let data = if cell["tmp_data"].is_null() {
+            bytes::Bytes::new()
+        } else {
+            let version_bytes = parse_version("Field `cell.data.version`", &cell["tmp_data"]["version"])?;
+            let receipt = Self::parse_receipt(script_parser, &cell["tmp_data"], source)?;
+            let mol_bytes = bytes::Bytes::from(receipt.as_slice().to_vec());
+
+            [version_bytes, mol_bytes].concat().into()
+        };
+
+        let cell_output = CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(lock_script.expect("lock script is required"))
+            .type_(ScriptOpt::new_builder().set(type_script).build())
+            .build();
+
+        Ok((cell_output, data))
+    }
+}
+
+impl CellParser for TickReceiptCell {
+    fn get_keyword(&self) -> String {
+        self.keyword.clone()
+    }
+
+    fn parse_cell_deps(
+        &self,
+        var_parser: Rc<RefCell<VarParser>>,
+        script_parser: &ScriptParser,
+        data: Value,
+        index: usize,
+    ) -> Result<(Byte, CellOutput, bytes::Bytes), Box<dyn StdError>> {
+        // parse cell_deps[].out_point as a mock cell
+        let (cell_output, cell_data) = Self::parse_cell(
+            var_parser.clone(),
+            script_parser,
+            data["out_point"].clone(),
+            Source::CellDep,
+        )
+        .map_err(|err| format!("Field `cell_deps[{}].out_point` parse failed: {}", index, err))?;
+
+        // parse cell_deps[].dep_type
+        let dep_type = util::parse_json_u8(&format!("cell_deps[{}].dep_type", index), &data["dep_type"], Some(0));
+        let dep_type = Byte::new(dep_type);
+
+        Ok((dep_type, cell_output, cell_data))
+    }
+
+    fn parse_inputs(
+        &self,
+        var_parser: Rc<RefCell<VarParser>>,
+        script_parser: &ScriptParser,
+        data: Value,
+        index: usize,
+    ) -> Result<(u64, CellOutput, bytes::Bytes), Box<dyn StdError>> {
+        // parse inputs[].previous_output as a mock cell
+        let (cell_output, cell_data) = Self::parse_cell(
+            var_parser.clone(),
+            script_parser,
+            data["previous_output"].clone(),
+            Source::Input,
+        )
+        .map_err(|err| format!("Field `inputs[{}].previous_output` parse failed: {}", index, err))?;
+
+        // parse inputs[].since
+        let since = util::parse_json_u64(&format!("inputs[{}].since", index), &data["since"], Some(0));
+
+        Ok((since, cell_output, cell_data))
+    }
+
+    fn parse_outputs(
+        &self,
+        var_parser: Rc<RefCell<VarParser>>,
+        script_parser: &ScriptParser,
+        data: Value,
+        index: usize,
+    ) -> Result<(CellOutput, bytes::Bytes), Box<dyn StdError>> {
+        // parse outputs[] as a mock cell
+        let (cell_output, cell_data) =
+            Self::parse_cell(var_parser.clone(), script_parser, data.clone(), Source::Output)
+                .map_err(|err| format!("Field `outputs[{}]` parse failed: {}", index, err))?;
+
+        Ok((cell_output, cell_data))
+    }
+}
@@ -204,8 +204,7 @@ impl XudtCell {
         let data = if cell["tmp_data"].is_null() {
             bytes::Bytes::new()
         } else {
-            let sudt_amount =
-                util::parse_json_u64("Field `cell.tmp_data.amount`", &cell["tmp_data"]["amount"], None) as u128;
+            let sudt_amount = util::parse_json_u128("Field `cell.tmp_data.amount`", &cell["tmp_data"]["amount"], None);
             let sudt_amount_bytes = sudt_amount.to_le_bytes().to_vec();
             bytes::Bytes::from(sudt_amount_bytes)
         };
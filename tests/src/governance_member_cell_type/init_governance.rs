@@ -1,10 +1,12 @@
 use serde_json::json;
+use types::constants::ConfigKey;
 
 use crate::util;
 use crate::util::constants::{
     ExpectedError, ALWAYS_SUCCESS_TYPE_ARGS, CONFIG_CELL_TYPE_ARGS, DEPLOY_LOCK_ARGS, FAKE_OMNI_LOCK_TYPE_ARGS,
     FAKE_SECPK1_BLAKE160_SIGNHASH_ALL_ARGS, GOVERNANCE_MEMBER_CELL_TYPE_ARGS, OWNER_LOCK_ARGS_1,
 };
+use crate::util::error::ErrorCode;
 
 #[test]
 fn test_governance_member_init_custodian() {
@@ -100,6 +102,583 @@ fn test_governance_member_init_custodian() {
     template_parser.parse_and_verify(tx, u64::MAX, None)
 }
 
+#[test]
+fn test_governance_member_init_custodian_with_owner_lock_reordered() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-secp256k1-blake160-signhash-all",
+                "type_args": FAKE_SECPK1_BLAKE160_SIGNHASH_ALL_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-secp256k1-blake160-signhash-all}}",
+                        "args": DEPLOY_LOCK_ARGS
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    }
+                },
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "{{type-id}}"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000001",
+                        "0xFF00000000000000000000000000000000000002",
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000005",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            "0x",
+            util::gen_action(0, "init_governance"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_governance_member_init_custodian_when_paused() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-secp256k1-blake160-signhash-all",
+                "type_args": FAKE_SECPK1_BLAKE160_SIGNHASH_ALL_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::GovernanceStatus, "0x00".to_string()),
+                        ])
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    }
+                },
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "{{type-id}}"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000001",
+                        "0xFF00000000000000000000000000000000000002",
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000005",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            util::gen_action(0, "init_governance"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ErrorCode::ActionPaused as i8))
+}
+
+#[test]
+fn challenge_governance_member_init_custodian_with_invalid_threshold() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-secp256k1-blake160-signhash-all",
+                "type_args": FAKE_SECPK1_BLAKE160_SIGNHASH_ALL_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    }
+                },
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "{{type-id}}"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 6,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000001",
+                        "0xFF00000000000000000000000000000000000002",
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000005",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            util::gen_action(0, "init_governance"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::CustodianThresholdIsInvalid as i8))
+}
+
+#[test]
+fn challenge_governance_member_init_custodian_with_invalid_require_first_n() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-secp256k1-blake160-signhash-all",
+                "type_args": FAKE_SECPK1_BLAKE160_SIGNHASH_ALL_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    }
+                },
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "{{type-id}}"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 4,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000001",
+                        "0xFF00000000000000000000000000000000000002",
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000005",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            util::gen_action(0, "init_governance"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::CustodianRequireFirstNIsInvalid as i8))
+}
+
+#[test]
+fn challenge_governance_member_init_custodian_with_invalid_member() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-secp256k1-blake160-signhash-all",
+                "type_args": FAKE_SECPK1_BLAKE160_SIGNHASH_ALL_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    }
+                },
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "{{type-id}}"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000001",
+                        "0xFF00000000000000000000000000000000000002",
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        // Not a 20 bytes blake160
+                        "0xFF000000000000000000000000000000000005",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            util::gen_action(0, "init_governance"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::CustodianMemberIsInvalid as i8))
+}
+
+#[test]
+fn challenge_governance_member_init_custodian_with_duplicated_members() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-secp256k1-blake160-signhash-all",
+                "type_args": FAKE_SECPK1_BLAKE160_SIGNHASH_ALL_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    }
+                },
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "{{type-id}}"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000001",
+                        "0xFF00000000000000000000000000000000000002",
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000004",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            util::gen_action(0, "init_governance"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::CustodianMemberIsDuplicated as i8))
+}
+
 #[test]
 fn test_governance_member_init_merchant() {
     let tx = json!({
@@ -319,7 +898,7 @@ fn chanllenge_governance_member_init_custodian_without_owner_lock() {
     });
 
     let mut template_parser = util::init_template_parser();
-    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::OwnerLockIsRequired as i8))
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::OwnerLockIsRequiredInInputs as i8))
 }
 
 #[test]
@@ -447,5 +1026,5 @@ fn challenge_governance_member_init_merchant_without_owner_lock() {
     });
 
     let mut template_parser = util::init_template_parser();
-    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::OwnerLockIsRequired as i8))
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::OwnerLockIsRequiredInInputs as i8))
 }
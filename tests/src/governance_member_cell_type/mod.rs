@@ -1,4 +1,5 @@
 pub mod init_governance;
+pub mod propose_custodians;
 pub mod update_custodians;
 pub mod update_merchants;
 pub mod update_owner;
@@ -1,12 +1,18 @@
 use serde_json::json;
+use types::constants::ConfigKey;
 
 use crate::util;
 use crate::util::constants::{
-    CONFIG_CELL_TYPE_ARGS, FAKE_OMNI_LOCK_TYPE_ARGS, GOVERNANCE_MEMBER_CELL_TYPE_ARGS, OWNER_LOCK_ARGS_1,
+    ExpectedError, CONFIG_CELL_TYPE_ARGS, FAKE_OMNI_LOCK_TYPE_ARGS, GOVERNANCE_MEMBER_CELL_TYPE_ARGS, OWNER_LOCK_ARGS_1,
 };
+use crate::util::error::ErrorCode;
+use crate::util::since_util::{self, SinceFlag};
 
 #[test]
 fn test_governance_member_update_custodians() {
+    let since = since_util::set_metric_flag(100, SinceFlag::Epoch);
+    let delay_since =
+        since_util::set_relative_flag(since_util::set_metric_flag(6, SinceFlag::Epoch), SinceFlag::Relative);
     let tx = json!({
         "cell_deps": [
             {
@@ -44,6 +50,7 @@ fn test_governance_member_update_custodians() {
         ],
         "inputs": [
             {
+                "since": since,
                 "previous_output": {
                     "tmp_type": "GovernanceMemberCell",
                     "capacity": 0,
@@ -73,6 +80,508 @@ fn test_governance_member_update_custodians() {
                         ]
                     }
                 }
+            },
+            {
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian_proposal",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "custodian_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "activation_epoch": 100,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                            "0xFF00000000000000000000000000000000000006",
+                            "0xFF00000000000000000000000000000000000007",
+                        ]
+                    }
+                },
+                "since": delay_since
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000005",
+                        // New custodians
+                        "0xFF00000000000000000000000000000000000006",
+                        "0xFF00000000000000000000000000000000000007",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            "0x",
+            util::gen_action(0, "update_custodians"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_governance_member_update_custodians_before_activation() {
+    let since = since_util::set_metric_flag(99, SinceFlag::Epoch);
+    let delay_since =
+        since_util::set_relative_flag(since_util::set_metric_flag(6, SinceFlag::Epoch), SinceFlag::Relative);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "since": since,
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000001",
+                            "0xFF00000000000000000000000000000000000002",
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                        ]
+                    }
+                }
+            },
+            {
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian_proposal",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "custodian_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "activation_epoch": 100,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                            "0xFF00000000000000000000000000000000000006",
+                            "0xFF00000000000000000000000000000000000007",
+                        ]
+                    }
+                },
+                "since": delay_since
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000005",
+                        // New custodians
+                        "0xFF00000000000000000000000000000000000006",
+                        "0xFF00000000000000000000000000000000000007",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            "0x",
+            util::gen_action(0, "update_custodians"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ExpectedError::CustodianProposalNotActivated as i8))
+}
+
+#[test]
+fn challenge_governance_member_update_custodians_immediately() {
+    let since = since_util::set_metric_flag(100, SinceFlag::Epoch);
+    let delay_since =
+        since_util::set_relative_flag(since_util::set_metric_flag(0, SinceFlag::Epoch), SinceFlag::Relative);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "since": since,
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000001",
+                            "0xFF00000000000000000000000000000000000002",
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                        ]
+                    }
+                }
+            },
+            {
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian_proposal",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "custodian_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "activation_epoch": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                            "0xFF00000000000000000000000000000000000006",
+                            "0xFF00000000000000000000000000000000000007",
+                        ]
+                    }
+                },
+                "since": delay_since
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000005",
+                        // New custodians
+                        "0xFF00000000000000000000000000000000000006",
+                        "0xFF00000000000000000000000000000000000007",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            "0x",
+            util::gen_action(0, "update_custodians"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(
+        tx,
+        u64::MAX,
+        Some(ExpectedError::CustodianProposalDelayNotReached as i8),
+    )
+}
+
+#[test]
+fn test_governance_member_update_custodians_with_cosign() {
+    let since = since_util::set_metric_flag(100, SinceFlag::Epoch);
+    let delay_since =
+        since_util::set_relative_flag(since_util::set_metric_flag(6, SinceFlag::Epoch), SinceFlag::Relative);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::CustodianCosignStatus, "0x01".to_string()),
+                        ])
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "since": since,
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000001",
+                            "0xFF00000000000000000000000000000000000002",
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                        ]
+                    }
+                }
+            },
+            {
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian_proposal",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "custodian_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "activation_epoch": 100,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                            "0xFF00000000000000000000000000000000000006",
+                            "0xFF00000000000000000000000000000000000007",
+                        ]
+                    }
+                },
+                "since": delay_since
+            },
+            {
+                "previous_output": {
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": util::gen_custodian_lock_args()
+                    }
+                },
             }
         ],
         "outputs": [
@@ -108,6 +617,8 @@ fn test_governance_member_update_custodians() {
             },
         ],
         "witnesses": [
+            "0x",
+            "0x",
             "0x",
             util::gen_action(0, "update_custodians"),
         ]
@@ -116,3 +627,156 @@ fn test_governance_member_update_custodians() {
     let mut template_parser = util::init_template_parser();
     template_parser.parse_and_verify(tx, u64::MAX, None)
 }
+
+#[test]
+fn challenge_governance_member_update_custodians_without_cosign() {
+    let since = since_util::set_metric_flag(100, SinceFlag::Epoch);
+    let delay_since =
+        since_util::set_relative_flag(since_util::set_metric_flag(6, SinceFlag::Epoch), SinceFlag::Relative);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::CustodianCosignStatus, "0x01".to_string()),
+                        ])
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "since": since,
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000001",
+                            "0xFF00000000000000000000000000000000000002",
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                        ]
+                    }
+                }
+            },
+            {
+                "previous_output": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "capacity": 0,
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian_proposal",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "custodian_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "activation_epoch": 100,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            "0xFF00000000000000000000000000000000000003",
+                            "0xFF00000000000000000000000000000000000004",
+                            "0xFF00000000000000000000000000000000000005",
+                            "0xFF00000000000000000000000000000000000006",
+                            "0xFF00000000000000000000000000000000000007",
+                        ]
+                    }
+                },
+                "since": delay_since
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "GovernanceMemberCell",
+                "capacity": 0,
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": OWNER_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{governance-member-cell-type}}",
+                    "args": {
+                        "role": "custodian",
+                        "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                    }
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "multisig_args": {
+                        "require_first_n": 0,
+                        "threshold": 3,
+                    },
+                    "members": [
+                        "0xFF00000000000000000000000000000000000003",
+                        "0xFF00000000000000000000000000000000000004",
+                        "0xFF00000000000000000000000000000000000005",
+                        // New custodians
+                        "0xFF00000000000000000000000000000000000006",
+                        "0xFF00000000000000000000000000000000000007",
+                    ]
+                }
+            },
+        ],
+        "witnesses": [
+            "0x",
+            "0x",
+            util::gen_action(0, "update_custodians"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ErrorCode::CustodianLockIsRequired as i8))
+}
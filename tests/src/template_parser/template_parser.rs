@@ -239,7 +239,8 @@ impl TemplateParser {
     /// These fields are all optional, and it will be compiled to a RawHeader object in molecule finally.
     fn parse_header_deps(&mut self, header_deps: Vec<Value>) -> Result<(), Box<dyn StdError>> {
         for (i, item) in header_deps.into_iter().enumerate() {
-            self.mock_block_header(&format!("header_deps[{}]", i), &item)?;
+            let block_hash = self.mock_block_header(&format!("header_deps[{}]", i), &item)?;
+            self.push_header_dep(block_hash);
         }
 
         Ok(())
@@ -328,6 +329,10 @@ impl TemplateParser {
     ///             "tmp_data": ...,""
     ///         },
     ///         "since": "0x...",
+    ///         "tmp_out_point": {
+    ///             "tx_hash": "0x...",
+    ///             "index": ...
+    ///         },
     ///     },
     ///     ...
     /// ]
@@ -346,6 +351,32 @@ impl TemplateParser {
                 },
             };
             debug!("Parsing inputs keyword = ... {}", keyword);
+            // The out point is mocked by the index of the input, unless it is specified by the tmp_out_point field.
+            let tmp_out_point = if item["tmp_out_point"].is_null() {
+                None
+            } else {
+                let tx_hash = util::parse_json_hex(
+                    &format!("inputs[{}].tmp_out_point.tx_hash", i),
+                    &item["tmp_out_point"]["tx_hash"],
+                    None,
+                );
+                let index = util::parse_json_u32(
+                    &format!("inputs[{}].tmp_out_point.index", i),
+                    &item["tmp_out_point"]["index"],
+                    Some(0),
+                );
+                Some(
+                    OutPoint::new_builder()
+                        .tx_hash(Byte32::from_slice(&tx_hash).expect("The tx_hash should be 32 bytes."))
+                        .index(index.pack())
+                        .build(),
+                )
+            };
+
+            // The input is committed in the block of the tmp_header field if it is specified, the header will be pushed
+            // into header_deps too.
+            let tmp_header = item["tmp_header"].clone();
+
             match self.cell_parsers.get(keyword) {
                 Some(cell_parser) => {
                     let (since, cell_output, cell_data) =
@@ -353,7 +384,17 @@ impl TemplateParser {
 
                     // debug!("inputs[{}]: {}", i, cell_output);
 
-                    let out_point = self.mock_confirmed_cell(i, cell_output, cell_data);
+                    let out_point = match tmp_out_point {
+                        Some(out_point) => self.mock_confirmed_cell_with_out_point(out_point, cell_output, cell_data),
+                        None => self.mock_confirmed_cell(i, cell_output, cell_data),
+                    };
+                    if !tmp_header.is_null() {
+                        let block_hash = self.mock_block_header(&format!("inputs[{}].tmp_header", i), &tmp_header)?;
+                        self.context
+                            .borrow_mut()
+                            .link_cell_with_block(out_point.clone(), block_hash.clone(), 0);
+                        self.push_header_dep(block_hash);
+                    }
                     let cell_input = CellInput::new_builder()
                         .previous_output(out_point.clone())
                         .since(since.pack())
@@ -465,7 +506,13 @@ impl TemplateParser {
         Ok(())
     }
 
-    fn mock_block_header(&self, field_name: &str, header: &Value) -> Result<(), Box<dyn StdError>> {
+    fn push_header_dep(&self, block_hash: Byte32) {
+        let mut builder = self.tx_builder.take();
+        builder = builder.header_dep(block_hash);
+        self.tx_builder.replace(builder);
+    }
+
+    fn mock_block_header(&self, field_name: &str, header: &Value) -> Result<Byte32, Box<dyn StdError>> {
         let version = util::parse_json_u32(&format!("{}.version", field_name), &header["version"], Some(0));
         let number = if header["number"].is_null() {
             util::parse_json_u64(&format!("{}.height", field_name), &header["height"], Some(0))
@@ -494,17 +541,27 @@ impl TemplateParser {
             .build();
         let header = Header::new_builder().raw(raw_header).nonce(Uint128::default()).build();
         let header_view = header.into_view();
+        let block_hash = header_view.hash();
 
         let mut context = self.context.borrow_mut();
         context.insert_header(header_view);
 
-        Ok(())
+        Ok(block_hash)
     }
 
     fn mock_confirmed_cell(&self, index: usize, cell: CellOutput, data: bytes::Bytes) -> OutPoint {
         let tx_hash = index_to_byte32(index);
         let out_point = OutPoint::new_builder().index(0u32.pack()).tx_hash(tx_hash).build();
 
+        self.mock_confirmed_cell_with_out_point(out_point, cell, data)
+    }
+
+    fn mock_confirmed_cell_with_out_point(
+        &self,
+        out_point: OutPoint,
+        cell: CellOutput,
+        data: bytes::Bytes,
+    ) -> OutPoint {
         let mut context = self.context.borrow_mut();
         context.create_cell_with_out_point(out_point.clone(), cell, data);
 
@@ -104,6 +104,21 @@ pub fn parse_json_u64(field_name: &str, field: &Value, default: Option<u64>) ->
     }
 }
 
+/// Parse u128 in JSON
+///
+/// Support both **number** and **string** format, the values larger than u64 can only be in string format.
+pub fn parse_json_u128(field_name: &str, field: &Value, default: Option<u128>) -> u128 {
+    if let Some(val) = field.as_u64() {
+        val as u128
+    } else if let Some(val) = field.as_str() {
+        val.replace('_', "")
+            .parse()
+            .unwrap_or_else(|_| panic!("{} should be u128 in string", field_name))
+    } else {
+        default.unwrap_or_else(|| panic!("{} is missing", field_name))
+    }
+}
+
 /// Parse u32 in JSON
 ///
 /// Support both **number** and **string** format.
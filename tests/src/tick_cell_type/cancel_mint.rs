@@ -0,0 +1,398 @@
+use serde_json::json;
+
+use crate::util;
+use crate::util::constants::{
+    ALWAYS_SUCCESS_TYPE_ARGS, CONFIG_CELL_TYPE_ARGS, DUMMY_TX_HASH, FAKE_OMNI_LOCK_TYPE_ARGS, MERCHANT_LOCK_ARGS_1,
+    MERCHANT_LOCK_ARGS_2, OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS, TYPE_ID_ARGS,
+};
+use crate::util::error::TickErrorCode;
+
+#[test]
+fn test_tick_cell_cancel_mint() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": TYPE_ID_ARGS,
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            }
+        ],
+        "witnesses":[
+            "0x",
+            "0x",
+            util::gen_action(0, "cancel_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_cancel_mint_by_other_merchant() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": TYPE_ID_ARGS,
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_2
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_2
+                },
+            }
+        ],
+        "witnesses":[
+            "0x",
+            "0x",
+            util::gen_action(0, "cancel_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::InvalidTickMerchantLock as i8))
+}
+
+#[test]
+fn test_tick_cell_cancel_mint_after_deadline() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "since": "1000",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": TYPE_ID_ARGS,
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                            // Absolute block number 1000
+                            "deadline": "1000",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            }
+        ],
+        "witnesses":[
+            "0x",
+            "0x",
+            util::gen_action(0, "cancel_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn test_tick_cell_cancel_mint_before_deadline() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "since": "999",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": TYPE_ID_ARGS,
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                            // Absolute block number 1000
+                            "deadline": "1000",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            }
+        ],
+        "witnesses":[
+            "0x",
+            "0x",
+            util::gen_action(0, "cancel_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
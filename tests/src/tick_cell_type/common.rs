@@ -3,26 +3,39 @@ use serde_json::{json, Value};
 use crate::util::constants::TYPE_ID_ARGS;
 
 pub fn gen_xudt_token_id() -> Value {
+    gen_xudt_token_id_of(TYPE_ID_ARGS)
+}
+
+pub fn gen_xudt_args() -> Value {
+    gen_xudt_args_of(TYPE_ID_ARGS)
+}
+
+pub fn gen_xudt_witness() -> Value {
+    gen_xudt_witness_of(TYPE_ID_ARGS)
+}
+
+/// Different owner args produce different owner script hashes, so they can be used to mock different kinds of tokens.
+pub fn gen_xudt_token_id_of(owner_args: &str) -> Value {
     json!({
         "code_hash": "{{xudt_owner.so}}",
-        "args": TYPE_ID_ARGS
+        "args": owner_args
     })
 }
 
-pub fn gen_xudt_args() -> Value {
+pub fn gen_xudt_args_of(owner_args: &str) -> Value {
     json!({
         "owner_script_hash": {
             "code_hash": "{{xudt_owner.so}}",
-            "args": TYPE_ID_ARGS
+            "args": owner_args
         }
     })
 }
 
-pub fn gen_xudt_witness() -> Value {
+pub fn gen_xudt_witness_of(owner_args: &str) -> Value {
     json!({
         "owner_script": {
             "code_hash": "{{xudt_owner.so}}",
-            "args": TYPE_ID_ARGS
+            "args": owner_args
         },
     })
 }
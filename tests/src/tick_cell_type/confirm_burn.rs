@@ -1,14 +1,15 @@
 use serde_json::json;
-use types::constants::Source;
+use types::constants::{ConfigKey, Source};
 
 use super::common::{gen_xudt_args, gen_xudt_token_id, gen_xudt_witness};
 use crate::util;
 use crate::util::constants::{
     ALWAYS_SUCCESS_TYPE_ARGS, CONFIG_CELL_TYPE_ARGS, CUSTODIAN_LOCK_ARGS_1, CUSTODIAN_LOCK_ARGS_2,
-    CUSTODIAN_LOCK_ARGS_3, CUSTODIAN_LOCK_ARGS_4, CUSTODIAN_LOCK_ARGS_5, DUMMY_TX_HASH, FAKE_OMNI_LOCK_TYPE_ARGS,
-    GOVERNANCE_MEMBER_CELL_TYPE_ARGS, MERCHANT_LOCK_ARGS_1, OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS,
-    XUDT_OWNER_TYPE_ARGS, XUDT_RCE_TYPE_ARGS,
+    CUSTODIAN_LOCK_ARGS_3, CUSTODIAN_LOCK_ARGS_4, CUSTODIAN_LOCK_ARGS_5, DUMMY_TX_HASH, ESCROW_TX_HASH,
+    FAKE_OMNI_LOCK_TYPE_ARGS, FEE_LOCK_ARGS, GOVERNANCE_MEMBER_CELL_TYPE_ARGS, MERCHANT_LOCK_ARGS_1,
+    MERCHANT_LOCK_ARGS_2, OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS, XUDT_OWNER_TYPE_ARGS, XUDT_RCE_TYPE_ARGS,
 };
+use crate::util::error::TickErrorCode;
 
 #[test]
 fn test_tick_cell_confirm_burn() {
@@ -164,7 +165,7 @@ fn test_tick_cell_confirm_burn() {
             }
         ],
         "witnesses":[
-            util::gen_tick_cell_witness_args(1, DUMMY_TX_HASH, Source::Input),
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
             {
                 "tmp_type": "xudt",
                 "lock": "0x",
@@ -178,3 +179,1267 @@ fn test_tick_cell_confirm_burn() {
     let mut template_parser = util::init_template_parser();
     template_parser.parse_and_verify(tx, u64::MAX, None)
 }
+
+#[test]
+fn test_tick_cell_confirm_burn_in_batch() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "2000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_2,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "XudtCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 3000
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "input_type": gen_xudt_witness()
+            },
+            "0x",
+            util::gen_action(0, "confirm_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn test_tick_cell_confirm_burn_with_escrow() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "tmp_out_point": {
+                    "tx_hash": ESCROW_TX_HASH,
+                    "index": 0
+                },
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                            "escrowed": true,
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "tmp_out_point": {
+                    "tx_hash": ESCROW_TX_HASH,
+                    "index": 1
+                },
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 1000
+                    }
+                },
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
+            "0x",
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "input_type": gen_xudt_witness()
+            },
+            util::gen_action(0, "confirm_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_confirm_burn_with_escrow_of_other_tick() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "tmp_out_point": {
+                    "tx_hash": ESCROW_TX_HASH,
+                    "index": 0
+                },
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                            "escrowed": true,
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "tmp_out_point": {
+                    "tx_hash": ESCROW_TX_HASH,
+                    // The escrow should be created right after the TickCell.
+                    "index": 2
+                },
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 1000
+                    }
+                },
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
+            "0x",
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "input_type": gen_xudt_witness()
+            },
+            util::gen_action(0, "confirm_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::EscrowCellMissing as i8))
+}
+
+#[test]
+fn test_tick_cell_confirm_burn_with_fee() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::FeeLock, util::gen_merchant_script(FEE_LOCK_ARGS)),
+                            (ConfigKey::FeeRate, util::bytes_to_hex(&100u16.to_le_bytes())),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "XudtCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 1010
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": FEE_LOCK_ARGS,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 10
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "input_type": gen_xudt_witness()
+            },
+            "0x",
+            util::gen_action(0, "confirm_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_confirm_burn_with_duplicated_nonce() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "nonce": 1,
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "nonce": 1,
+                            "token_id": gen_xudt_token_id(),
+                            "value": "2000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "XudtCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 3000
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "input_type": gen_xudt_witness()
+            },
+            "0x",
+            util::gen_action(0, "confirm_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::DuplicatedTickNonce as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_burn_with_invalid_payout() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "XudtCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 1000
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_burn_payout_witness_args("0x80000001", "0x00", Source::Input),
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "input_type": gen_xudt_witness()
+            },
+            "0x",
+            util::gen_action(0, "confirm_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::InvalidBurnPayout as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_burn_after_deadline() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "since": "1000",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                            // Absolute block number 1000
+                            "deadline": "1000",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "XudtCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 1000
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_burn_payout_witness_args("0x80000001", DUMMY_TX_HASH, Source::Input),
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "input_type": gen_xudt_witness()
+            },
+            "0x",
+            util::gen_action(0, "confirm_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::TickDeadlinePassed as i8))
+}
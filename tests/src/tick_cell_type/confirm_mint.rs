@@ -1,17 +1,25 @@
 use serde_json::json;
+use types::constants::ConfigKey;
 
-use super::common::{gen_xudt_args, gen_xudt_token_id, gen_xudt_witness};
+use super::common::{
+    gen_xudt_args, gen_xudt_args_of, gen_xudt_token_id, gen_xudt_token_id_of, gen_xudt_witness, gen_xudt_witness_of,
+};
 use crate::util;
 use crate::util::constants::{
     ALWAYS_SUCCESS_TYPE_ARGS, CONFIG_CELL_TYPE_ARGS, CUSTODIAN_LOCK_ARGS_1, CUSTODIAN_LOCK_ARGS_2,
-    CUSTODIAN_LOCK_ARGS_3, CUSTODIAN_LOCK_ARGS_4, CUSTODIAN_LOCK_ARGS_5, DUMMY_TX_HASH, FAKE_OMNI_LOCK_TYPE_ARGS,
-    GOVERNANCE_MEMBER_CELL_TYPE_ARGS, MERCHANT_LOCK_ARGS_1, OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS,
-    XUDT_OWNER_TYPE_ARGS, XUDT_RCE_TYPE_ARGS,
+    CUSTODIAN_LOCK_ARGS_3, CUSTODIAN_LOCK_ARGS_4, CUSTODIAN_LOCK_ARGS_5, DEPOSIT_REGISTRY_CELL_TYPE_ARGS,
+    DUMMY_TX_HASH, DUMMY_TX_HASH_2, FAKE_OMNI_LOCK_TYPE_ARGS, FEE_LOCK_ARGS, GOVERNANCE_MEMBER_CELL_TYPE_ARGS,
+    MERCHANT_LOCK_ARGS_1, MERCHANT_LOCK_ARGS_2, MINT_QUOTA_CELL_TYPE_ARGS, OWNER_LOCK_ARGS_1,
+    RESERVE_ATTESTATION_TYPE_ARGS, TICK_CELL_TYPE_ARGS, TICK_RECEIPT_TYPE_ARGS, TYPE_ID_ARGS_2, TYPE_ID_CODE_HASH,
+    XUDT_OWNER_TYPE_ARGS, XUDT_RCE_TYPE_ARGS, XUDT_SUPPLY_CELL_TYPE_ARGS,
 };
+use crate::util::error::{ErrorCode, TickErrorCode};
 
 #[test]
 fn test_tick_cell_confirm_mint() {
     let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
     let tx = json!({
         "cell_deps": [
             {
@@ -131,6 +139,18 @@ fn test_tick_cell_confirm_mint() {
                         "args": custodian_lock_args
                     },
                 }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
             }
         ],
         "outputs": [
@@ -153,6 +173,16 @@ fn test_tick_cell_confirm_mint() {
                     "code_hash": "{{fake-omni-lock}}",
                     "args": custodian_lock_args
                 },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
             }
         ],
         "witnesses":[
@@ -162,6 +192,7 @@ fn test_tick_cell_confirm_mint() {
                 "output_type": gen_xudt_witness()
             },
             "0x",
+            registry_witness,
             util::gen_action(0, "confirm_mint"),
         ]
     });
@@ -169,3 +200,4438 @@ fn test_tick_cell_confirm_mint() {
     let mut template_parser = util::init_template_parser();
     template_parser.parse_and_verify(tx, u64::MAX, None)
 }
+
+#[test]
+fn challenge_tick_cell_confirm_mint_with_deposit_registry_not_locked_by_tick() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxgxxxx",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        // Simulate the DepositRegistryCell being locked by the custodians only
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::InvalidDepositRegistryUpdate as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_without_deposit_registry() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::DepositRegistryCellTypeArgs, "0x".to_string()),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ErrorCode::ParseCellDataFailed as i8))
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_in_batch() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) = util::gen_deposit_registry_update(
+        vec![],
+        vec![("0x80000001", DUMMY_TX_HASH), ("0x80000001", DUMMY_TX_HASH_2)],
+    );
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "2000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_2,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH_2,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_2,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 2000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_with_multiple_tokens() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) = util::gen_deposit_registry_update(
+        vec![],
+        vec![("0x80000001", DUMMY_TX_HASH), ("0x80000001", DUMMY_TX_HASH_2)],
+    );
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id_of(TYPE_ID_ARGS_2),
+                            "value": "2000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_2,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH_2,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_2,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args_of(TYPE_ID_ARGS_2)
+                },
+                "tmp_data": {
+                    "amount": 2000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness_of(TYPE_ID_ARGS_2)
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_before_deadline() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "since": "999",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                            // Absolute block number 1000
+                            "deadline": "1000",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_with_extra_minted_xudt() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_2,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::MintedXudtAmountNotMatch as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_with_consumed_deposit() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![("0x80000001", DUMMY_TX_HASH)], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ErrorCode::InvalidSmtProof as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_after_deadline() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "since": "1000",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                            // Absolute block number 1000
+                            "deadline": "1000",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::TickDeadlinePassed as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_with_overflowed_value() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) = util::gen_deposit_registry_update(
+        vec![],
+        vec![("0x80000001", DUMMY_TX_HASH), ("0x80000001", DUMMY_TX_HASH_2)],
+    );
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "170141183460469231731687303715884105728",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "170141183460469231731687303715884105728",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_2,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH_2,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": "170141183460469231731687303715884105728"
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_2,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": "170141183460469231731687303715884105728"
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::AmountOverflow as i8))
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_with_mint_quota() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::MintQuotaCellTypeArgs, MINT_QUOTA_CELL_TYPE_ARGS.to_string()),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "MintQuotaCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": MINT_QUOTA_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "quotas": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "merchant": {
+                                    "code_hash": "{{fake-omni-lock}}",
+                                    "args": MERCHANT_LOCK_ARGS_1,
+                                },
+                                "max_request_value": 1000,
+                                "cap": 2000,
+                                "period": 86400,
+                                "period_start": 0,
+                                "minted": 500
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "MintQuotaCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": MINT_QUOTA_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "quotas": [
+                        {
+                        "token_id": gen_xudt_token_id(),
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "max_request_value": 1000,
+                        "cap": 2000,
+                        "period": 86400,
+                        "period_start": 0,
+                        "minted": 1500
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_exceeding_mint_quota() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::MintQuotaCellTypeArgs, MINT_QUOTA_CELL_TYPE_ARGS.to_string()),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "MintQuotaCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": MINT_QUOTA_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "quotas": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "merchant": {
+                                    "code_hash": "{{fake-omni-lock}}",
+                                    "args": MERCHANT_LOCK_ARGS_1,
+                                },
+                                "max_request_value": 1000,
+                                "cap": 2000,
+                                "period": 86400,
+                                "period_start": 0,
+                                "minted": 1500
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "MintQuotaCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": MINT_QUOTA_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "quotas": [
+                        {
+                        "token_id": gen_xudt_token_id(),
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "max_request_value": 1000,
+                        "cap": 2000,
+                        "period": 86400,
+                        "period_start": 0,
+                        "minted": 2500
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::MintQuotaExceeded as i8))
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_with_fee() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::FeeLock, util::gen_merchant_script(FEE_LOCK_ARGS)),
+                            (ConfigKey::FeeRate, util::bytes_to_hex(&100u16.to_le_bytes())),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 990
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": FEE_LOCK_ARGS,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 10
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_with_receipt() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::TickReceiptType, util::gen_always_success_script(TICK_RECEIPT_TYPE_ARGS)),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "TickReceiptCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{always_success}}",
+                    "args": TICK_RECEIPT_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "tick": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    },
+                    "outcome": "confirmed",
+                    "custodian_lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_with_xudt_supply() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::XudtSupplyCellTypeArgs, XUDT_SUPPLY_CELL_TYPE_ARGS.to_string()),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "supplies": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "supply": 5000,
+                                "max_supply": 6000
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "supplies": [
+                        {
+                            "token_id": gen_xudt_token_id(),
+                            "supply": 6000,
+                            "max_supply": 6000
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_with_invalid_xudt_supply() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::XudtSupplyCellTypeArgs, XUDT_SUPPLY_CELL_TYPE_ARGS.to_string()),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "supplies": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "supply": 5000,
+                                "max_supply": 6000
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "supplies": [
+                        {
+                            "token_id": gen_xudt_token_id(),
+                            "supply": 5000,
+                            "max_supply": 6000
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::InvalidXudtSupplyUpdate as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_with_xudt_supply_not_locked_by_tick() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::XudtSupplyCellTypeArgs, XUDT_SUPPLY_CELL_TYPE_ARGS.to_string()),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "previous_output": {
+                    // Simulate the XudtSupplyCell being created by anyone else
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "supplies": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "supply": 5000,
+                                "max_supply": 6000
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "supplies": [
+                        {
+                            "token_id": gen_xudt_token_id(),
+                            "supply": 6000,
+                            "max_supply": 6000
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::InvalidXudtSupplyUpdate as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_with_duplicated_xudt_supply() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::XudtSupplyCellTypeArgs, XUDT_SUPPLY_CELL_TYPE_ARGS.to_string()),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "supplies": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "supply": 5000,
+                                "max_supply": 6000
+                            },
+                            // Simulate a second XudtSupply of the same token with a lower supply
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "supply": 0,
+                                "max_supply": 6000
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "supplies": [
+                        {
+                            "token_id": gen_xudt_token_id(),
+                            "supply": 6000,
+                            "max_supply": 6000
+                        },
+                        {
+                            "token_id": gen_xudt_token_id(),
+                            "supply": 0,
+                            "max_supply": 6000
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::InvalidXudtSupplyUpdate as i8))
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_with_reserve_attestation() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::XudtSupplyCellTypeArgs, XUDT_SUPPLY_CELL_TYPE_ARGS.to_string()),
+                            (ConfigKey::ReserveAttestationType, util::gen_always_success_script(RESERVE_ATTESTATION_TYPE_ARGS)),
+                            (ConfigKey::ReserveAttestationMaxAge, util::bytes_to_hex(&3600u64.to_le_bytes())),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ReserveAttestationCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{always_success}}",
+                        "args": RESERVE_ATTESTATION_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "coin_type": "0x80000001",
+                        "reserve": 6000,
+                        "block_height": 800000,
+                        "timestamp": 1700000000u64
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "tmp_header": {
+                    "timestamp": 1700001000000u64
+                },
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "supplies": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "supply": 5000,
+                                "max_supply": 6000
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "supplies": [
+                        {
+                            "token_id": gen_xudt_token_id(),
+                            "supply": 6000,
+                            "max_supply": 6000
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_exceeding_reserve() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::XudtSupplyCellTypeArgs, XUDT_SUPPLY_CELL_TYPE_ARGS.to_string()),
+                            (ConfigKey::ReserveAttestationType, util::gen_always_success_script(RESERVE_ATTESTATION_TYPE_ARGS)),
+                            (ConfigKey::ReserveAttestationMaxAge, util::bytes_to_hex(&3600u64.to_le_bytes())),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ReserveAttestationCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{always_success}}",
+                        "args": RESERVE_ATTESTATION_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "coin_type": "0x80000001",
+                        "reserve": 5500,
+                        "block_height": 800000,
+                        "timestamp": 1700000000u64
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "tmp_header": {
+                    "timestamp": 1700001000000u64
+                },
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "supplies": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "supply": 5000,
+                                "max_supply": 6000
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "supplies": [
+                        {
+                            "token_id": gen_xudt_token_id(),
+                            "supply": 6000,
+                            "max_supply": 6000
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::ReserveExceeded as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_with_stale_reserve_attestation() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        // Simulate the TickCell being created in time but confirmed long after the attestation
+        "header_deps": [
+            {
+                "timestamp": 1700010000000u64
+            }
+        ],
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::XudtSupplyCellTypeArgs, XUDT_SUPPLY_CELL_TYPE_ARGS.to_string()),
+                            (ConfigKey::ReserveAttestationType, util::gen_always_success_script(RESERVE_ATTESTATION_TYPE_ARGS)),
+                            (ConfigKey::ReserveAttestationMaxAge, util::bytes_to_hex(&3600u64.to_le_bytes())),
+                        ])
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ReserveAttestationCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{always_success}}",
+                        "args": RESERVE_ATTESTATION_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "coin_type": "0x80000001",
+                        "reserve": 6000,
+                        "block_height": 800000,
+                        "timestamp": 1700000000u64
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "tmp_header": {
+                    "timestamp": 1700001000000u64
+                },
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "supplies": [
+                            {
+                                "token_id": gen_xudt_token_id(),
+                                "supply": 5000,
+                                "max_supply": 6000
+                            }
+                        ]
+                    }
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            },
+            {
+                "tmp_type": "XudtSupplyCell",
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": XUDT_SUPPLY_CELL_TYPE_ARGS
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "supplies": [
+                        {
+                            "token_id": gen_xudt_token_id(),
+                            "supply": 6000,
+                            "max_supply": 6000
+                        }
+                    ]
+                }
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            "0x",
+            util::gen_action(0, "confirm_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::ReserveAttestationStale as i8))
+}
@@ -0,0 +1,993 @@
+use serde_json::json;
+
+use super::common::{gen_xudt_args, gen_xudt_token_id, gen_xudt_witness};
+use crate::util;
+use crate::util::constants::{
+    ALWAYS_SUCCESS_TYPE_ARGS, CONFIG_CELL_TYPE_ARGS, CUSTODIAN_LOCK_ARGS_1, CUSTODIAN_LOCK_ARGS_2,
+    CUSTODIAN_LOCK_ARGS_3, CUSTODIAN_LOCK_ARGS_4, CUSTODIAN_LOCK_ARGS_5, DEPOSIT_REGISTRY_CELL_TYPE_ARGS,
+    DUMMY_TX_HASH, DUMMY_TX_HASH_2, FAKE_OMNI_LOCK_TYPE_ARGS, GOVERNANCE_MEMBER_CELL_TYPE_ARGS, MERCHANT_LOCK_ARGS_1,
+    OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS, TYPE_ID_CODE_HASH, XUDT_OWNER_TYPE_ARGS, XUDT_RCE_TYPE_ARGS,
+};
+use crate::util::error::{ErrorCode, TickErrorCode};
+
+#[test]
+fn test_tick_cell_confirm_mint_partial() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 600
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint_partial"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn test_tick_cell_confirm_mint_partial_with_residual() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": gen_xudt_token_id(),
+                        "value": "400",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 600
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            "0x",
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            registry_witness,
+            util::gen_action(0, "confirm_mint_partial"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_partial_with_registered_residual() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    // Simulate the residual TickCell whose deposit has been registered by a partial confirmation
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![("0x80000001", DUMMY_TX_HASH)], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 600
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint_partial"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ErrorCode::InvalidSmtProof as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_partial_with_modified_residual() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": gen_xudt_token_id(),
+                        "value": "400",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH_2,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 600
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            "0x",
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            registry_witness,
+            util::gen_action(0, "confirm_mint_partial"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::InvalidResidualTickCell as i8))
+}
+
+#[test]
+fn challenge_tick_cell_confirm_mint_partial_after_deadline() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let (registry_data, new_registry_data, registry_witness) =
+        util::gen_deposit_registry_update(vec![], vec![("0x80000001", DUMMY_TX_HASH)]);
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "since": "1000",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "Tick":{
+                            "tick_type": "mint",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                            // Absolute block number 1000
+                            "deadline": "1000",
+                        }
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": registry_data
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1,
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 600
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "type": {
+                    "code_hash": TYPE_ID_CODE_HASH,
+                    "args": DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+                },
+                "tmp_data": new_registry_data
+            }
+        ],
+        "witnesses":[
+            {
+                "tmp_type": "xudt",
+                "lock": "0x",
+                "output_type": gen_xudt_witness()
+            },
+            "0x",
+            registry_witness,
+            util::gen_action(0, "confirm_mint_partial"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::TickDeadlinePassed as i8))
+}
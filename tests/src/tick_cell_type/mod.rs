@@ -1,6 +1,10 @@
+pub mod cancel_burn;
+pub mod cancel_mint;
 mod common;
 pub mod confirm_burn;
 pub mod confirm_mint;
+pub mod confirm_mint_partial;
+pub mod force_close_tick;
 pub mod reject_burn;
 pub mod reject_mint;
 pub mod request_burn;
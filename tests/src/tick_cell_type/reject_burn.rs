@@ -6,9 +6,10 @@ use crate::util;
 use crate::util::constants::{
     ALWAYS_SUCCESS_TYPE_ARGS, CONFIG_CELL_TYPE_ARGS, CUSTODIAN_LOCK_ARGS_1, CUSTODIAN_LOCK_ARGS_2,
     CUSTODIAN_LOCK_ARGS_3, CUSTODIAN_LOCK_ARGS_4, CUSTODIAN_LOCK_ARGS_5, DUMMY_TX_HASH, FAKE_OMNI_LOCK_TYPE_ARGS,
-    GOVERNANCE_MEMBER_CELL_TYPE_ARGS, MERCHANT_LOCK_ARGS_1, OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS,
-    XUDT_OWNER_TYPE_ARGS, XUDT_RCE_TYPE_ARGS,
+    GOVERNANCE_MEMBER_CELL_TYPE_ARGS, MERCHANT_LOCK_ARGS_1, MERCHANT_LOCK_ARGS_2, OWNER_LOCK_ARGS_1,
+    TICK_CELL_TYPE_ARGS, XUDT_OWNER_TYPE_ARGS, XUDT_RCE_TYPE_ARGS,
 };
+use crate::util::error::TickErrorCode;
 
 #[test]
 fn test_tick_cell_reject_burn() {
@@ -188,3 +189,375 @@ fn test_tick_cell_reject_burn() {
     let mut template_parser = util::init_template_parser();
     template_parser.parse_and_verify(tx, u64::MAX, None)
 }
+
+#[test]
+fn challenge_tick_cell_reject_burn_with_tokens_moved_to_other_lock() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "XudtCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 1100
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1000
+                }
+            },
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_2
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 100
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_tick_cell_witness_args(1, DUMMY_TX_HASH, Source::Input),
+            "0x",
+            "0x",
+            util::gen_action(0, "reject_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::UnexpectedXudtTransfer as i8))
+}
+
+#[test]
+fn challenge_tick_cell_reject_burn_with_more_tokens_returned() {
+    let custodian_lock_args = util::gen_custodian_lock_args();
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "xudt_rce",
+                "type_args": XUDT_RCE_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "xudt_owner.so",
+                "type_args": XUDT_OWNER_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "custodian",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "multisig_args": {
+                            "require_first_n": 0,
+                            "threshold": 3,
+                        },
+                        "members": [
+                            CUSTODIAN_LOCK_ARGS_1,
+                            CUSTODIAN_LOCK_ARGS_2,
+                            CUSTODIAN_LOCK_ARGS_3,
+                            CUSTODIAN_LOCK_ARGS_4,
+                            CUSTODIAN_LOCK_ARGS_5,
+                        ]
+                    }
+                }
+            },
+        ],
+        "inputs": [
+            {
+                "tmp_type": "TickCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{tick-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "Tick": {
+                            "tick_type": "burn",
+                            "token_id": gen_xudt_token_id(),
+                            "value": "1000",
+                            "merchant": {
+                                "code_hash": "{{fake-omni-lock}}",
+                                "args": MERCHANT_LOCK_ARGS_1,
+                            },
+                            "coin_type": "0x80000001",
+                            "tx_hash": DUMMY_TX_HASH,
+                            "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        }
+                    }
+                },
+            },
+            {
+                "tmp_type": "XudtCell",
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                    "type": {
+                        "code_hash": "{{xudt_rce}}",
+                        "args": gen_xudt_args()
+                    },
+                    "tmp_data": {
+                        "amount": 1100
+                    }
+                },
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": custodian_lock_args
+                    },
+                }
+            },
+        ],
+        "outputs": [
+            {
+                "tmp_type": "XudtCell",
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+                "type": {
+                    "code_hash": "{{xudt_rce}}",
+                    "args": gen_xudt_args()
+                },
+                "tmp_data": {
+                    "amount": 1100
+                }
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": MERCHANT_LOCK_ARGS_1
+                },
+            },
+            {
+                "lock": {
+                    "code_hash": "{{fake-omni-lock}}",
+                    "args": custodian_lock_args
+                },
+            }
+        ],
+        "witnesses":[
+            util::gen_tick_cell_witness_args(1, DUMMY_TX_HASH, Source::Input),
+            "0x",
+            "0x",
+            util::gen_action(0, "reject_burn"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::ReturnedXudtAmountNotMatch as i8))
+}
@@ -1,11 +1,14 @@
 use serde_json::json;
+use types::constants::ConfigKey;
 
 use crate::util;
 use crate::util::constants::{
-    ALWAYS_SUCCESS_TYPE_ARGS, CONFIG_CELL_TYPE_ARGS, DUMMY_TX_HASH, FAKE_OMNI_LOCK_TYPE_ARGS,
+    ALWAYS_SUCCESS_TYPE_ARGS, CONFIG_CELL_TYPE_ARGS, DUMMY_LOCK_ARGS, DUMMY_TX_HASH, FAKE_OMNI_LOCK_TYPE_ARGS,
     GOVERNANCE_MEMBER_CELL_TYPE_ARGS, MERCHANT_LOCK_ARGS_1, MERCHANT_LOCK_ARGS_2, MERCHANT_LOCK_ARGS_3,
-    MERCHANT_LOCK_ARGS_4, MERCHANT_LOCK_ARGS_5, OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS, TYPE_ID_ARGS,
+    MERCHANT_LOCK_ARGS_4, MERCHANT_LOCK_ARGS_5, MINT_QUOTA_CELL_TYPE_ARGS, OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS,
+    TYPE_ID_ARGS, TYPE_ID_CODE_HASH,
 };
+use crate::util::error::{ErrorCode, TickErrorCode};
 
 #[test]
 fn test_tick_cell_request_mint() {
@@ -52,6 +55,7 @@ fn test_tick_cell_request_mint() {
                     }
                 }
             },
+            util::gen_custodian_cell_dep(),
             {
                 "out_point": {
                     "tmp_type": "GovernanceMemberCell",
@@ -111,7 +115,1076 @@ fn test_tick_cell_request_mint() {
                         },
                         "coin_type": "0x80000001",
                         "tx_hash": DUMMY_TX_HASH,
-                        "receipt_addr": "bc1p5d7rjq7g6rdk2yhzks9smlaqtedr4dekq08ge8ztwac72sfr9rusxgxxxx",
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            "0x",
+            util::gen_action(0, "request_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn test_tick_cell_request_mint_with_merchant_lock_at_any_position() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            util::gen_custodian_cell_dep(),
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_1),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_2),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_3),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_4),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_5),
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": DUMMY_LOCK_ARGS
+                    },
+                }
+            },
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": TYPE_ID_ARGS,
+                        "value": "1000",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            "0x",
+            "0x",
+            util::gen_action(0, "request_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_request_mint_when_paused() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::RequestMintStatus, "0x00".to_string()),
+                            (ConfigKey::ConfirmMintStatus, "0x00".to_string()),
+                        ])
+                    }
+                }
+            },
+            util::gen_custodian_cell_dep(),
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_1),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_2),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_3),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_4),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_5),
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": TYPE_ID_ARGS,
+                        "value": "1000",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            "0x",
+            util::gen_action(0, "request_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ErrorCode::ActionPaused as i8))
+}
+
+#[test]
+fn challenge_tick_cell_request_mint_with_frozen_token() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::FrozenTokenIds, TYPE_ID_ARGS.to_string()),
+                        ])
+                    }
+                }
+            },
+            util::gen_custodian_cell_dep(),
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_1),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_2),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_3),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_4),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_5),
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": TYPE_ID_ARGS,
+                        "value": "1000",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            "0x",
+            util::gen_action(0, "request_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ErrorCode::TokenFrozen as i8))
+}
+
+#[test]
+fn test_tick_cell_request_mint_with_sharded_merchants() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            util::gen_custodian_cell_dep(),
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_2),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_3),
+                        ]
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000003"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_1),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_4),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_5),
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": TYPE_ID_ARGS,
+                        "value": "1000",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            "0x",
+            util::gen_action(0, "request_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_request_mint_with_shard_of_other_parent() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            util::gen_custodian_cell_dep(),
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_2),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_3),
+                        ]
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000003"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000009",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_1),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_4),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_5),
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": TYPE_ID_ARGS,
+                        "value": "1000",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            "0x",
+            util::gen_action(0, "request_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(ErrorCode::MerchantParentIdMismatch as i8))
+}
+
+#[test]
+fn test_tick_cell_request_mint_with_merchant_members_root() {
+    let merchants = [MERCHANT_LOCK_ARGS_1, MERCHANT_LOCK_ARGS_4, MERCHANT_LOCK_ARGS_5];
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            util::gen_custodian_cell_dep(),
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_2),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_3),
+                        ]
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000003"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 1,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members_root": util::gen_merchant_members_root(&merchants)
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": TYPE_ID_ARGS,
+                        "value": "1000",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            util::gen_merchant_membership_witness(&merchants, MERCHANT_LOCK_ARGS_1),
+            util::gen_action(0, "request_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, None)
+}
+
+#[test]
+fn challenge_tick_cell_request_mint_exceeding_max_request_value() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs_with(vec![
+                            (ConfigKey::MintQuotaCellTypeArgs, MINT_QUOTA_CELL_TYPE_ARGS.to_string()),
+                        ])
+                    }
+                }
+            },
+            util::gen_custodian_cell_dep(),
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_1),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_2),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_3),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_4),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_5),
+                        ]
+                    }
+                }
+            },
+            {
+                "out_point": {
+                    "tmp_type": "MintQuotaCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": TYPE_ID_CODE_HASH,
+                        "args": MINT_QUOTA_CELL_TYPE_ARGS
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "quotas": [
+                            {
+                                "token_id": TYPE_ID_ARGS,
+                                "merchant": {
+                                    "code_hash": "{{fake-omni-lock}}",
+                                    "args": MERCHANT_LOCK_ARGS_1,
+                                },
+                                "max_request_value": 500,
+                                "cap": 2000,
+                                "period": 86400,
+                                "period_start": 0,
+                                "minted": 0
+                            }
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 0,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": TYPE_ID_ARGS,
+                        "value": "1000",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                    }
+                }
+            }
+        ],
+        "witnesses":[
+            "0x",
+            util::gen_action(0, "request_mint"),
+        ]
+    });
+
+    let mut template_parser = util::init_template_parser();
+    template_parser.parse_and_verify(tx, u64::MAX, Some(TickErrorCode::MintRequestValueExceeded as i8))
+}
+
+#[test]
+fn test_tick_cell_request_mint_v1() {
+    let tx = json!({
+        "cell_deps": [
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "always_success",
+                "type_args": ALWAYS_SUCCESS_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "tick-cell-type",
+                "type_args": TICK_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "governance-member-cell-type",
+                "type_args": GOVERNANCE_MEMBER_CELL_TYPE_ARGS
+            },
+            {
+                "tmp_type": "deployed_contract",
+                "tmp_file_name": "fake-omni-lock",
+                "type_args": FAKE_OMNI_LOCK_TYPE_ARGS,
+            },
+            {
+                "tmp_type": "contract",
+                "tmp_file_name": "config-cell-type",
+                "type_args": CONFIG_CELL_TYPE_ARGS
+            },
+            {
+                "out_point": {
+                    "tmp_type": "ConfigCell",
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": OWNER_LOCK_ARGS_1
+                    },
+                    "type": {
+                        "code_hash": "{{config-cell-type}}",
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "configs": util::gen_configs()
+                    }
+                }
+            },
+            util::gen_custodian_cell_dep(),
+            {
+                "out_point": {
+                    "tmp_type": "GovernanceMemberCell",
+                    "lock": {
+                        "code_hash": "{{always_success}}",
+                    },
+                    "type": {
+                        "code_hash": "{{governance-member-cell-type}}",
+                        "args": {
+                            "role": "merchant",
+                            "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                        }
+                    },
+                    "tmp_data": {
+                        "version": 0,
+                        "parent_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "members": [
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_1),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_2),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_3),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_4),
+                            util::gen_merchant_script(MERCHANT_LOCK_ARGS_5),
+                        ]
+                    }
+                }
+            },
+
+        ],
+        "inputs": [
+            {
+                "previous_output": {
+                    "lock": {
+                        "code_hash": "{{fake-omni-lock}}",
+                        "args": MERCHANT_LOCK_ARGS_1
+                    },
+                }
+            }
+        ],
+        "outputs": [
+            {
+                "tmp_type": "TickCell",
+                "lock": {
+                    "code_hash": "{{always_success}}",
+                },
+                "type": {
+                    "code_hash": "{{tick-cell-type}}",
+                },
+                "tmp_data": {
+                    "version": 1,
+                    "Tick":{
+                        "tick_type": "mint",
+                        "token_id": TYPE_ID_ARGS,
+                        "value": "1000",
+                        "merchant": {
+                            "code_hash": "{{fake-omni-lock}}",
+                            "args": MERCHANT_LOCK_ARGS_1,
+                        },
+                        "coin_type": "0x80000001",
+                        "tx_hash": DUMMY_TX_HASH,
+                        "receipt_addr": "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                        "nonce": 1,
+                        "memo": "The first request of the merchant",
+                        "deadline": "1000",
                     }
                 }
             }
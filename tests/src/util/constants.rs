@@ -30,6 +30,11 @@ pub const FAKE_XUDT_OWNER_TYPE_ARGS: &str = "0x0066";
 pub const FAKE_XUDT_OWNER_TYPE_ID: &str = "0xbdeb1edc5c81a32c1098e9703d90f2b0e47e8f199c815634dae03e49033e5bde";
 
 pub const TYPE_ID_CODE_HASH: &str = "0x00000000000000000000000000000000000000000000000000545950455f4944";
+pub const DEPOSIT_REGISTRY_CELL_TYPE_ARGS: &str = "0x0505050505050505050505050505050505050505050505050505050505050505";
+pub const MINT_QUOTA_CELL_TYPE_ARGS: &str = "0x0606060606060606060606060606060606060606060606060606060606060606";
+pub const XUDT_SUPPLY_CELL_TYPE_ARGS: &str = "0x0909090909090909090909090909090909090909090909090909090909090909";
+pub const TICK_RECEIPT_TYPE_ARGS: &str = "0x0707070707070707070707070707070707070707070707070707070707070707";
+pub const RESERVE_ATTESTATION_TYPE_ARGS: &str = "0x0808080808080808080808080808080808080808080808080808080808080808";
 
 // ⚠️ The maximum cycles on-chain is 3500_000_000.
 pub const MAX_CYCLES: u64 = u64::MAX;
@@ -55,14 +60,25 @@ pub const MERCHANT_LOCK_ARGS_3: &str = "0xEE000000000000000000000000000000000000
 pub const MERCHANT_LOCK_ARGS_4: &str = "0xEE00000000000000000000000000000000000004";
 pub const MERCHANT_LOCK_ARGS_5: &str = "0xEE00000000000000000000000000000000000005";
 
+pub const FEE_LOCK_ARGS: &str = "0xDD00000000000000000000000000000000000001";
+
 pub const TYPE_ID_ARGS: &str = "0xff000000000000000000000000000000000000000000000000000000000000ff";
+pub const TYPE_ID_ARGS_2: &str = "0xff000000000000000000000000000000000000000000000000000000000002ff";
 
 pub const DUMMY_TX_HASH: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
+pub const DUMMY_TX_HASH_2: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+pub const ESCROW_TX_HASH: &str = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
 
 #[repr(i8)]
 pub enum ExpectedError {
-    OwnerLockIsRequired = 15,
+    OwnerLockIsRequiredInInputs = 33,
     CustodianLockMustNotInMerchants = 17,
+    CustodianProposalNotActivated = 20,
+    CustodianMemberIsInvalid = 22,
+    CustodianMemberIsDuplicated = 23,
+    CustodianThresholdIsInvalid = 25,
+    CustodianRequireFirstNIsInvalid = 26,
+    CustodianProposalDelayNotReached = 27,
 }
 
 lazy_static! {
@@ -7,4 +7,40 @@ pub enum ErrorCode {
     ItemMissing,
     LengthNotEnough,
     Encoding,
+    ParseCellDataFailed = 12,
+    CustodianLockIsRequired = 17,
+    InvalidSmtProof = 30,
+    ActionPaused = 31,
+    TokenFrozen = 32,
+    OwnerLockIsRequiredInInputs = 33,
+    MerchantParentIdMismatch = 34,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(i8)]
+pub enum TickErrorCode {
+    InvalidTickMerchantLock = 9,
+    InvalidDepositRegistryUpdate = 20,
+    MintedXudtAmountNotMatch = 17,
+    EscrowCellMissing = 23,
+    MintRequestValueExceeded = 26,
+    MintQuotaExceeded = 27,
+    InvalidReceiptAddress = 31,
+    DuplicatedTickNonce = 33,
+    ReturnedXudtAmountNotMatch = 35,
+    UnexpectedXudtTransfer = 36,
+    InvalidResidualTickCell = 38,
+    InvalidTickReceipt = 39,
+    InvalidBurnPayout = 40,
+    TickCapacityNotReturned = 41,
+    InvalidXudtSupplyUpdate = 42,
+    XudtSupplyExceeded = 43,
+    InvalidReserveAttestation = 44,
+    ReserveAttestationStale = 45,
+    ReserveExceeded = 46,
+    TickDeadlinePassed = 47,
+    InvalidEscrowedFlag = 48,
+    AmountOverflow = 49,
+    DepositRegistryCellSpentByInvalidAction = 50,
+    XudtSupplyCellSpentByInvalidAction = 51,
 }
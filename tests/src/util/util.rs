@@ -7,17 +7,26 @@ use ckb_testtool::ckb_types::core::ScriptHashType;
 use ckb_testtool::ckb_types::packed::{BytesOpt, WitnessArgs};
 use ckb_testtool::ckb_types::prelude::{Builder, Pack};
 use serde_json::{json, Value};
+use sparse_merkle_tree::default_store::DefaultStore;
+use sparse_merkle_tree::{SparseMerkleTree, H256};
 use types::constants::{ConfigKey, Source};
-use types::packed::{Byte, Byte32, Bytes, Script};
+use types::packed::{
+    BurnPayout, Byte, Byte32, Bytes, Script, SmtProof, SmtUpdateAction, SmtUpdateItem, SmtUpdateItemVec,
+};
 use types::prelude::Entity;
+use types::smt::{
+    deposit_key, Blake2bHasher, SMT_UPDATE_INSERT, SMT_UPDATE_REMOVE, SMT_VALUE_EXISTING, SMT_VALUE_NOT_EXISTING,
+};
+use types::util::blake2b_256;
 
 use crate::custom_parser;
 use crate::template_parser::{util as parser_util, CellParser, TemplateParser};
 use crate::util::constants::{
     ALWAYS_SUCCESS_TYPE_ARGS, ALWAYS_SUCCESS_TYPE_ID, CUSTODIAN_LOCK_ARGS_1, CUSTODIAN_LOCK_ARGS_2,
-    CUSTODIAN_LOCK_ARGS_3, CUSTODIAN_LOCK_ARGS_4, CUSTODIAN_LOCK_ARGS_5, FAKE_OMNI_LOCK_TYPE_ARGS,
-    FAKE_OMNI_LOCK_TYPE_ID, GOVERNANCE_MEMBER_CELL_TYPE_ARGS, GOVERNANCE_MEMBER_CELL_TYPE_ID, TICK_CELL_TYPE_ARGS,
-    TICK_CELL_TYPE_ID, XUDT_OWNER_TYPE_ARGS, XUDT_OWNER_TYPE_ID, XUDT_RCE_TYPE_ARGS, XUDT_RCE_TYPE_ID,
+    CUSTODIAN_LOCK_ARGS_3, CUSTODIAN_LOCK_ARGS_4, CUSTODIAN_LOCK_ARGS_5, DEPOSIT_REGISTRY_CELL_TYPE_ARGS,
+    FAKE_OMNI_LOCK_TYPE_ARGS, FAKE_OMNI_LOCK_TYPE_ID, GOVERNANCE_MEMBER_CELL_TYPE_ARGS, GOVERNANCE_MEMBER_CELL_TYPE_ID,
+    OWNER_LOCK_ARGS_1, TICK_CELL_TYPE_ARGS, TICK_CELL_TYPE_ID, XUDT_OWNER_TYPE_ARGS, XUDT_OWNER_TYPE_ID,
+    XUDT_RCE_TYPE_ARGS, XUDT_RCE_TYPE_ID,
 };
 
 pub fn hex_to_bytes(input: &str) -> Vec<u8> {
@@ -74,6 +83,10 @@ pub fn init_template_parser() -> TemplateParser {
         Box::new(custom_parser::ConfigCell::new()),
         Box::new(custom_parser::XudtCell::new()),
         Box::new(custom_parser::TickCell::new()),
+        Box::new(custom_parser::MintQuotaCell::new()),
+        Box::new(custom_parser::TickReceiptCell::new()),
+        Box::new(custom_parser::XudtSupplyCell::new()),
+        Box::new(custom_parser::ReserveAttestationCell::new()),
     ];
 
     TemplateParser::new(cell_parsers, vec![])
@@ -109,6 +122,25 @@ pub fn gen_tick_cell_witness_args(coin_type: u32, tx_hash: &str, source: Source)
     bytes_to_hex(witness_args.as_slice())
 }
 
+/// Generate the witness of TickCell which contains the BurnPayout, the coin_type should be the same as the TickCell.
+pub fn gen_burn_payout_witness_args(coin_type: &str, tx_hash: &str, source: Source) -> String {
+    let payout = BurnPayout::new_builder()
+        .coin_type(Bytes::from(hex_to_bytes(coin_type)))
+        .tx_hash(Bytes::from(hex_to_bytes(tx_hash)))
+        .build();
+    let data = payout.as_slice().to_vec();
+
+    let mut builder = WitnessArgs::new_builder();
+    if source == Source::Input {
+        builder = builder.input_type(bytes_to_bytes_opt(data));
+    } else {
+        builder = builder.output_type(bytes_to_bytes_opt(data));
+    }
+    let witness_args = builder.build();
+
+    bytes_to_hex(witness_args.as_slice())
+}
+
 pub fn gen_configs() -> Value {
     json!([
         [ConfigKey::SystemStatus as u32, "0x01"],
@@ -132,11 +164,27 @@ pub fn gen_configs() -> Value {
         [ConfigKey::OmniLockTypeArgs as u32, FAKE_OMNI_LOCK_TYPE_ARGS],
         [ConfigKey::XudtOwnerTypeId as u32, XUDT_OWNER_TYPE_ID],
         [ConfigKey::XudtOwnerTypeArgs as u32, XUDT_OWNER_TYPE_ARGS],
+        [
+            ConfigKey::DepositRegistryCellTypeArgs as u32,
+            DEPOSIT_REGISTRY_CELL_TYPE_ARGS
+        ],
         // [ConfigKey::XudtOwnerTypeId as u32, FAKE_XUDT_OWNER_TYPE_ID],
         // [ConfigKey::XudtOwnerTypeArgs as u32, FAKE_XUDT_OWNER_TYPE_ARGS],
     ])
 }
 
+/// Generate the default configs with the extra key/value pairs, a key already in the defaults is overwritten.
+pub fn gen_configs_with(extra: Vec<(ConfigKey, String)>) -> Value {
+    let mut configs = gen_configs();
+    let configs_mut = configs.as_array_mut().unwrap();
+    for (key, value) in extra {
+        configs_mut.retain(|config| config[0] != json!(key as u32));
+        configs_mut.push(json!([key as u32, value]));
+    }
+
+    configs
+}
+
 pub fn gen_custodian_lock_args() -> String {
     let custodian_lock_args = parser_util::build_omni_lock_multisig_args(
         0,
@@ -153,6 +201,40 @@ pub fn gen_custodian_lock_args() -> String {
     bytes_to_hex(&custodian_lock_args)
 }
 
+/// The custodian GovernanceMemberCell in cell_deps, whose cell_id is the parent_id of the merchant shards.
+pub fn gen_custodian_cell_dep() -> Value {
+    json!({
+        "out_point": {
+            "tmp_type": "GovernanceMemberCell",
+            "lock": {
+                "code_hash": "{{fake-omni-lock}}",
+                "args": OWNER_LOCK_ARGS_1
+            },
+            "type": {
+                "code_hash": "{{governance-member-cell-type}}",
+                "args": {
+                    "role": "custodian",
+                    "cell_id": "0x0000000000000000000000000000000000000000000000000000000000000001"
+                }
+            },
+            "tmp_data": {
+                "version": 0,
+                "multisig_args": {
+                    "require_first_n": 0,
+                    "threshold": 3,
+                },
+                "members": [
+                    CUSTODIAN_LOCK_ARGS_1,
+                    CUSTODIAN_LOCK_ARGS_2,
+                    CUSTODIAN_LOCK_ARGS_3,
+                    CUSTODIAN_LOCK_ARGS_4,
+                    CUSTODIAN_LOCK_ARGS_5,
+                ]
+            }
+        }
+    })
+}
+
 pub fn gen_merchant_script(args: &str) -> String {
     let args = hex_to_bytes(args);
     let type_id = Byte32::from_slice(&hex_to_bytes(FAKE_OMNI_LOCK_TYPE_ID))
@@ -166,3 +248,173 @@ pub fn gen_merchant_script(args: &str) -> String {
 
     bytes_to_hex(script.as_slice())
 }
+
+pub fn gen_always_success_script(args: &str) -> String {
+    let args = hex_to_bytes(args);
+    let type_id = Byte32::from_slice(&hex_to_bytes(ALWAYS_SUCCESS_TYPE_ID))
+        .expect("The ALWAYS_SUCCESS_TYPE_ID should be 32 bytes constant.");
+
+    let script = Script::new_builder()
+        .code_hash(type_id)
+        .hash_type(Byte::new(ScriptHashType::Type as u8))
+        .args(Bytes::from(args))
+        .build();
+
+    bytes_to_hex(script.as_slice())
+}
+
+/// Generate the data of DepositRegistryCell before and after the deposits are inserted, and the witness which contains
+/// the SmtUpdateAction. Each deposit is a tuple of (coin_type, tx_hash) in hex.
+pub fn gen_deposit_registry_update(
+    consumed: Vec<(&str, &str)>,
+    deposits: Vec<(&str, &str)>,
+) -> (String, String, String) {
+    let mut tree: SparseMerkleTree<Blake2bHasher, H256, DefaultStore<H256>> = SparseMerkleTree::default();
+    for (coin_type, tx_hash) in consumed {
+        let key = deposit_key(&hex_to_bytes(coin_type), &hex_to_bytes(tx_hash));
+        tree.update(key.into(), SMT_VALUE_EXISTING.into())
+            .expect("The consumed deposit should be inserted.");
+    }
+    let old_root = *tree.root();
+
+    let keys = deposits
+        .iter()
+        .map(|(coin_type, tx_hash)| H256::from(deposit_key(&hex_to_bytes(coin_type), &hex_to_bytes(tx_hash))))
+        .collect::<Vec<_>>();
+    let proof = tree
+        .merkle_proof(keys.clone())
+        .and_then(|proof| proof.compile(keys.clone()))
+        .expect("The proof of deposits should be generated.");
+
+    let mut updates = SmtUpdateItemVec::new_builder();
+    for key in keys.iter() {
+        tree.update(*key, SMT_VALUE_EXISTING.into())
+            .expect("The deposit should be inserted.");
+
+        let key_bytes: [u8; 32] = (*key).into();
+        updates = updates.push(
+            SmtUpdateItem::new_builder()
+                .key(Byte32::from(key_bytes))
+                .packed_values(Byte::new(SMT_UPDATE_INSERT))
+                .build(),
+        );
+    }
+    let new_root = *tree.root();
+
+    let proof_bytes: Vec<u8> = proof.into();
+    let action = SmtUpdateAction::new_builder()
+        .updates(updates.build())
+        .proof(
+            SmtProof::new_builder()
+                .set(proof_bytes.into_iter().map(Byte::new).collect())
+                .build(),
+        )
+        .build();
+    let witness_args = WitnessArgs::new_builder()
+        .input_type(bytes_to_bytes_opt(action.as_slice().to_vec()))
+        .build();
+
+    let gen_data = |root: H256| bytes_to_hex(&[vec![0u8], root.as_slice().to_vec()].concat());
+
+    (
+        gen_data(old_root),
+        gen_data(new_root),
+        bytes_to_hex(witness_args.as_slice()),
+    )
+}
+
+fn gen_merchant_members_tree(merchants: &[&str]) -> SparseMerkleTree<Blake2bHasher, H256, DefaultStore<H256>> {
+    let mut tree: SparseMerkleTree<Blake2bHasher, H256, DefaultStore<H256>> = SparseMerkleTree::default();
+    for merchant in merchants {
+        tree.update(gen_merchant_key(merchant), SMT_VALUE_EXISTING.into())
+            .expect("The merchant should be inserted.");
+    }
+    tree
+}
+
+/// The key of a merchant in the SMT of GovernanceMemberCell(merchant) is the lock hash of the merchant.
+fn gen_merchant_key(merchant: &str) -> H256 {
+    H256::from(blake2b_256(hex_to_bytes(&gen_merchant_script(merchant))))
+}
+
+fn gen_smt_proof(proof_bytes: Vec<u8>) -> SmtProof {
+    SmtProof::new_builder()
+        .set(proof_bytes.into_iter().map(Byte::new).collect())
+        .build()
+}
+
+/// Generate the SMT root of the merchants, each merchant is the lock args of the fake omni-lock in hex.
+pub fn gen_merchant_members_root(merchants: &[&str]) -> String {
+    let tree = gen_merchant_members_tree(merchants);
+    bytes_to_hex(tree.root().as_slice())
+}
+
+/// Generate the witness of the merchant input which contains the SmtProof of the merchant in the merchants.
+pub fn gen_merchant_membership_witness(merchants: &[&str], merchant: &str) -> String {
+    let tree = gen_merchant_members_tree(merchants);
+    let keys = vec![gen_merchant_key(merchant)];
+    let proof = tree
+        .merkle_proof(keys.clone())
+        .and_then(|proof| proof.compile(keys))
+        .expect("The proof of merchant should be generated.");
+
+    let witness_args = WitnessArgs::new_builder()
+        .input_type(bytes_to_bytes_opt(gen_smt_proof(proof.into()).as_slice().to_vec()))
+        .build();
+
+    bytes_to_hex(witness_args.as_slice())
+}
+
+/// Generate the SMT roots of the merchants before and after updating, and the witness of GovernanceMemberCell(merchant)
+/// which contains the SmtUpdateAction.
+pub fn gen_merchant_members_update(
+    merchants: &[&str],
+    inserted: &[&str],
+    removed: &[&str],
+) -> (String, String, String) {
+    let mut tree = gen_merchant_members_tree(merchants);
+    let old_root = *tree.root();
+
+    let changes = inserted
+        .iter()
+        .map(|merchant| (gen_merchant_key(merchant), SMT_VALUE_EXISTING, SMT_UPDATE_INSERT))
+        .chain(
+            removed
+                .iter()
+                .map(|merchant| (gen_merchant_key(merchant), SMT_VALUE_NOT_EXISTING, SMT_UPDATE_REMOVE)),
+        )
+        .collect::<Vec<_>>();
+    let keys = changes.iter().map(|(key, _, _)| *key).collect::<Vec<_>>();
+    let proof = tree
+        .merkle_proof(keys.clone())
+        .and_then(|proof| proof.compile(keys))
+        .expect("The proof of merchants should be generated.");
+
+    let mut updates = SmtUpdateItemVec::new_builder();
+    for (key, value, packed_values) in changes {
+        tree.update(key, value.into()).expect("The merchant should be updated.");
+
+        let key_bytes: [u8; 32] = key.into();
+        updates = updates.push(
+            SmtUpdateItem::new_builder()
+                .key(Byte32::from(key_bytes))
+                .packed_values(Byte::new(packed_values))
+                .build(),
+        );
+    }
+    let new_root = *tree.root();
+
+    let action = SmtUpdateAction::new_builder()
+        .updates(updates.build())
+        .proof(gen_smt_proof(proof.into()))
+        .build();
+    let witness_args = WitnessArgs::new_builder()
+        .input_type(bytes_to_bytes_opt(action.as_slice().to_vec()))
+        .build();
+
+    (
+        bytes_to_hex(old_root.as_slice()),
+        bytes_to_hex(new_root.as_slice()),
+        bytes_to_hex(witness_args.as_slice()),
+    )
+}